@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use num::{CheckedAdd, CheckedMul, CheckedSub, Zero};
+
+// integer whose overflow poisons every result depending on it, except that it times zero is zero, results that are not poisoned are exact
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct Checked<T>(Option<T>);
+
+impl<T> Checked<T> {
+    pub fn new(value: T) -> Checked<T> { Checked(Some(value)) }
+
+    pub fn overflow() -> Checked<T> { Checked(None) }
+
+    // `None` after an overflow
+    pub fn value(self) -> Option<T> { self.0 }
+
+    pub fn is_overflow(&self) -> bool { self.0.is_none() }
+}
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self { Checked::new(value) }
+}
+
+impl<T: Display> Display for Checked<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "overflow"),
+        }
+    }
+}
+
+
+impl<T: CheckedAdd> Add for Checked<T> {
+    type Output = Checked<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Checked(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_add(&b)))
+    }
+}
+
+impl<T: CheckedSub> Sub for Checked<T> {
+    type Output = Checked<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Checked(self.0.zip(rhs.0).and_then(|(a, b)| a.checked_sub(&b)))
+    }
+}
+
+impl<T: CheckedMul + Zero> Mul for Checked<T> {
+    type Output = Checked<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.0, rhs.0) {
+            (Some(a), _) | (_, Some(a)) if a.is_zero() => Checked::new(a),
+            (a, b) => Checked(a.zip(b).and_then(|(a, b)| a.checked_mul(&b))),
+        }
+    }
+}
+
+// only signed integers, so that unsigned ones do not pretend to have additive inverses, signed ones are no ring either
+macro_rules! neg_impls {
+    ($($t:ty),+) => {$(
+impl Neg for Checked<$t> {
+    type Output = Checked<$t>;
+
+    fn neg(self) -> Self::Output {
+        Checked(self.0.and_then(|a| a.checked_neg()))
+    }
+}
+    )+};
+}
+
+neg_impls!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,171 @@
+use num::{BigInt, BigUint, Integer, One, Signed, Zero};
+use num::integer::Roots;
+use num::rational::Ratio;
+use crate::modular::Zp;
+
+// `(residue, modulus)` pairs describe `x ≡ residue (mod modulus)`, moduli are positive
+
+// the combined congruence, `None` when the two are inconsistent; moduli need not be coprime
+pub fn crt_pair(first: (BigInt, BigInt), second: (BigInt, BigInt)) -> Option<(BigInt, BigInt)> {
+    let ((r1, m1), (r2, m2)) = (first, second);
+    let gcd = m1.extended_gcd(&m2);
+    let difference = &r2 - &r1;
+    if !difference.is_multiple_of(&gcd.gcd) {
+        return None;
+    }
+    let step = &m2 / &gcd.gcd;
+    let lcm = &m1 * &step;
+    // `gcd.x · m1 ≡ gcd (mod m2)`
+    let k = (difference / &gcd.gcd * gcd.x).mod_floor(&step);
+    Some(((r1 + m1 * k).mod_floor(&lcm), lcm))
+}
+
+pub fn crt(congruences: impl IntoIterator<Item=(BigInt, BigInt)>) -> Option<(BigInt, BigInt)> {
+    congruences.into_iter().try_fold((BigInt::zero(), BigInt::one()), crt_pair)
+}
+
+// representative in `(-modulus / 2, modulus / 2]`
+pub fn symmetric_residue(residue: &BigInt, modulus: &BigInt) -> BigInt {
+    let residue = residue.mod_floor(modulus);
+    if &residue * 2 > *modulus { residue - modulus } else { residue }
+}
+
+// the fraction `a / b` with `a ≡ b·residue (mod modulus)` and `|a|, b ≤ √(modulus / 2)`, if there is one
+pub fn rational_reconstruction(residue: &BigInt, modulus: &BigInt) -> Option<Ratio<BigInt>> {
+    let bound = Roots::sqrt(&(modulus / 2));
+    let (mut r0, mut r1) = (modulus.clone(), residue.mod_floor(modulus));
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+    while r1 > bound {
+        let quotient = &r0 / &r1;
+        let r2 = &r0 - &quotient * &r1;
+        let t2 = &t0 - &quotient * &t1;
+        (r0, r1, t0, t1) = (r1, r2, t1, t2);
+    }
+    if t1.is_zero() || t1.abs() > bound || !r1.gcd(&t1).is_one() {
+        return None;
+    }
+    Some(Ratio::new(r1, t1))
+}
+
+
+// a computation over any prime field `Zp<P>` of the multi-modular driver
+pub trait ModularComputation {
+    fn compute<const P: u64>(&self) -> Zp<P>;
+}
+
+macro_rules! primes {
+    ($($p:literal),+) => {
+pub const PRIMES: &[u64] = &[$($p),+];
+
+fn residues<C: ModularComputation + ?Sized>(computation: &C) -> impl Iterator<Item=(BigInt, BigInt)> + '_ {
+    let runs: &[fn(&C) -> u64] = &[$(|computation| computation.compute::<$p>().value()),+];
+    runs.iter().zip(PRIMES).map(move |(run, &p)| (BigInt::from(run(computation)), BigInt::from(p)))
+}
+    };
+}
+
+// the largest primes below 2⁶²
+primes!(
+    4611686018427387847, 4611686018427387817, 4611686018427387787, 4611686018427387761,
+    4611686018427387751, 4611686018427387737, 4611686018427387733, 4611686018427387709,
+    4611686018427387701, 4611686018427387631, 4611686018427387617, 4611686018427387587,
+    4611686018427387461, 4611686018427387421, 4611686018427387409, 4611686018427387329,
+    4611686018427387323, 4611686018427387301, 4611686018427387271, 4611686018427387241,
+    4611686018427387139, 4611686018427387131, 4611686018427387127, 4611686018427387113,
+    4611686018427387091, 4611686018427387073, 4611686018427386981, 4611686018427386923,
+    4611686018427386911, 4611686018427386903, 4611686018427386897, 4611686018427386887
+);
+
+// runs over primes until their product exceeds `required`, `None` when `PRIMES` do not suffice
+fn combined_residue<C: ModularComputation + ?Sized>(computation: &C, required: &BigInt) -> Option<(BigInt, BigInt)> {
+    let mut combined = (BigInt::zero(), BigInt::one());
+    for congruence in residues(computation) {
+        combined = crt_pair(combined, congruence)?;
+        if combined.1 > *required {
+            return Some(combined);
+        }
+    }
+    None
+}
+
+// the integer result of a computation with `|result| ≤ bound`
+pub fn multi_modular_integer<C: ModularComputation + ?Sized>(computation: &C, bound: &BigUint) -> Option<BigInt> {
+    let required = BigInt::from(bound.clone()) * 2;
+    let (residue, modulus) = combined_residue(computation, &required)?;
+    Some(symmetric_residue(&residue, &modulus))
+}
+
+// the rational result of a computation with numerator and denominator at most `bound` in absolute value
+pub fn multi_modular_rational<C: ModularComputation + ?Sized>(computation: &C, bound: &BigUint) -> Option<Ratio<BigInt>> {
+    let bound = BigInt::from(bound.clone());
+    let required = &bound * &bound * 2;
+    let (residue, modulus) = combined_residue(computation, &required)?;
+    rational_reconstruction(&residue, &modulus)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigUint, One};
+    use num::rational::Ratio;
+    use crate::crt::{crt, crt_pair, ModularComputation, multi_modular_integer, multi_modular_rational, rational_reconstruction};
+    use crate::fibonacci::fibonacci;
+    use crate::matrix::FiniteMatrix;
+    use crate::matrix::finite::Shape;
+    use crate::modular::Zp;
+    use crate::structures::ring_like::{div, plus, zero};
+
+    fn congruence(residue: i64, modulus: i64) -> (BigInt, BigInt) {
+        (BigInt::from(residue), BigInt::from(modulus))
+    }
+
+    #[test]
+    fn combine() {
+        assert_eq!(crt_pair(congruence(2, 3), congruence(3, 5)), Some(congruence(8, 15)));
+        assert_eq!(crt_pair(congruence(2, 6), congruence(8, 10)), Some(congruence(8, 30)));
+        assert_eq!(crt_pair(congruence(1, 4), congruence(2, 6)), None);
+        assert_eq!(crt([congruence(2, 3), congruence(3, 5), congruence(2, 7)]), Some(congruence(23, 105)));
+        assert_eq!(crt([]), Some(congruence(0, 1)));
+    }
+
+    #[test]
+    fn reconstruction() {
+        let modulus = BigInt::from(1_000_003);
+        let residue = BigInt::from(-3) * BigInt::from(7).modinv(&modulus).unwrap();
+        assert_eq!(rational_reconstruction(&residue, &modulus), Some(Ratio::new(BigInt::from(-3), BigInt::from(7))));
+    }
+
+    struct Fibonacci(u64);
+
+    impl ModularComputation for Fibonacci {
+        fn compute<const P: u64>(&self) -> Zp<P> { fibonacci(self.0) }
+    }
+
+    struct Determinant(Vec<i64>, usize);
+
+    impl ModularComputation for Determinant {
+        fn compute<const P: u64>(&self) -> Zp<P> {
+            let shape = Shape { height: self.1, width: self.1 };
+            FiniteMatrix::from_iter(shape, self.0.iter().map(|&x| Zp::from_signed(x))).determinant()
+        }
+    }
+
+    struct Harmonic(u64);
+
+    impl ModularComputation for Harmonic {
+        fn compute<const P: u64>(&self) -> Zp<P> {
+            (1..=self.0).map(|k| div(Zp::new(1), Zp::new(k))).fold(zero(), plus)
+        }
+    }
+
+    #[test]
+    fn multi_modular() {
+        let bound = BigUint::one() << 300;
+        assert_eq!(multi_modular_integer(&Fibonacci(400), &bound), Some(fibonacci::<BigInt>(400)));
+        assert_eq!(multi_modular_integer(&Fibonacci(400), &(BigUint::one() << 5000)), None);
+        let determinant = Determinant(vec![0, 1, 2, 1, 0, 3, 4, -3, 8], 3);
+        assert_eq!(multi_modular_integer(&determinant, &BigUint::from(1000u32)), Some(BigInt::from(-2)));
+        let harmonic = multi_modular_rational(&Harmonic(20), &BigUint::from(100_000_000u32));
+        assert_eq!(harmonic, Some(Ratio::new(BigInt::from(55835135), BigInt::from(15519504))));
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use num::BigInt;
+use crate::crt::crt;
+use crate::implementations::modular::ZmodUnits;
+use crate::modular::Zmod;
+use crate::power::monoid::pow;
+use crate::primality::{carmichael, factorize, totient};
+use crate::structures::group_like::{Group, Monoid};
+
+fn is_identity<G: Monoid<T>, T: PartialEq>(element: &T) -> bool {
+    *element == G::identity()
+}
+
+// the order of `element` in a monoid of finite exponent dividing `group_order`
+pub fn order<G: Monoid<T>, T: Clone + PartialEq>(element: T, group_order: u64) -> u64 {
+    order_with_factorization::<G, T>(element, &factorize(group_order))
+}
+
+// as `order` with the group order given by its prime factorization
+pub fn order_with_factorization<G: Monoid<T>, T: Clone + PartialEq>(element: T, factorization: &[(u64, u32)]) -> u64 {
+    let mut result = factorization.iter().map(|&(p, k)| p.pow(k)).product::<u64>();
+    for &(p, k) in factorization {
+        for _ in 0..k {
+            if !is_identity::<G, T>(&pow::<G, T>(element.clone(), result / p)) {
+                break;
+            }
+            result /= p;
+        }
+    }
+    result
+}
+
+// the least `x < order` with `baseˣ = target`, `order` is a multiple of the order of `base`
+pub fn baby_step_giant_step<G: Group<T>, T: Clone + Eq + Hash>(base: T, target: T, order: u64) -> Option<u64> {
+    let steps = order.isqrt() + u64::from(order.isqrt().pow(2) < order);
+    let mut table = HashMap::new();
+    let mut power = G::identity();
+    for j in 0..steps {
+        table.entry(power.clone()).or_insert(j);
+        power = G::operation(power, base.clone());
+    }
+    let giant_step = G::inverse(power);
+    let mut current = target;
+    for i in 0..steps {
+        if let Some(j) = table.get(&current) {
+            return Some(i * steps + j).filter(|&x| x < order);
+        }
+        current = G::operation(current, giant_step.clone());
+    }
+    None
+}
+
+// discrete logarithm in a group of prime power order `pᵏ` generated by `base`
+fn prime_power_log<G: Group<T>, T: Clone + Eq + Hash>(base: T, target: T, p: u64, k: u32) -> Option<u64> {
+    let generator = pow::<G, T>(base.clone(), p.pow(k - 1));
+    let mut result = 0;
+    for digit in 0..k {
+        let shifted = G::operation(G::inverse(pow::<G, T>(base.clone(), result)), target.clone());
+        let projection = pow::<G, T>(shifted, p.pow(k - 1 - digit));
+        result += baby_step_giant_step::<G, T>(generator.clone(), projection, p)? * p.pow(digit);
+    }
+    Some(result)
+}
+
+// Pohlig–Hellman, the least `x` with `baseˣ = target`, `group_order` is a multiple of the order of `base`
+pub fn discrete_log<G: Group<T>, T: Clone + Eq + Hash>(base: T, target: T, group_order: u64) -> Option<u64> {
+    let element_order = order::<G, T>(base.clone(), group_order);
+    let congruences = factorize(element_order).into_iter()
+        .map(|(p, k)| {
+            let cofactor = element_order / p.pow(k);
+            let base = pow::<G, T>(base.clone(), cofactor);
+            let target = pow::<G, T>(target.clone(), cofactor);
+            prime_power_log::<G, T>(base, target, p, k).map(|x| (BigInt::from(x), BigInt::from(p.pow(k))))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let (result, _) = crt(congruences)?;
+    let result = u64::try_from(result).unwrap();
+    (pow::<G, T>(base, result) == target).then_some(result)
+}
+
+
+// the first candidate generating a cyclic group of order `group_order`
+pub fn primitive_root<G: Monoid<T>, T: Clone + PartialEq>(candidates: impl IntoIterator<Item=T>, group_order: u64) -> Option<T> {
+    let primes = factorize(group_order);
+    candidates.into_iter().find(|candidate| {
+        is_identity::<G, T>(&pow::<G, T>(candidate.clone(), group_order))
+            && primes.iter().all(|&(p, _)| !is_identity::<G, T>(&pow::<G, T>(candidate.clone(), group_order / p)))
+    })
+}
+
+// the least generator of the units modulo `M`, `None` when they are not cyclic
+pub fn primitive_root_mod<const M: u64>() -> Option<Zmod<M>> {
+    let group_order = totient(M);
+    if M == 0 || carmichael(M) != group_order {
+        return None;
+    }
+    let units = (1..M).map(Zmod::new).filter(|x| x.try_inverse().is_some());
+    primitive_root::<ZmodUnits<M>, _>(units, group_order)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::cyclic::{baby_step_giant_step, discrete_log, order, primitive_root, primitive_root_mod};
+    use crate::galois::GaloisField;
+    use crate::implementations::modular::ZmodUnits;
+    use crate::modular::Zmod;
+    use crate::power::{group, monoid};
+    use crate::structures::ring_like::Semiring;
+
+    type Multi<T> = <T as Semiring>::Multi;
+    type Z = Zmod<1000003>;
+    type Units = ZmodUnits<1000003>;
+
+    #[test]
+    fn exponentiation() {
+        assert_eq!(monoid::pow::<Units, _>(Z::new(2), 1000002), Z::new(1));
+        assert_eq!(group::pow::<Units, _>(Z::new(2), -1), Z::new(500002));
+        assert_eq!(monoid::pow::<Multi<u64>, _>(3, 4), 81);
+        assert_eq!(monoid::pow::<<u64 as Semiring>::Sum, _>(3, 4), 12);
+        assert_eq!(group::pow::<<i64 as Semiring>::Sum, _>(3, -4), -12);
+    }
+
+    #[test]
+    fn element_order() {
+        assert_eq!(order::<ZmodUnits<101>, _>(Zmod::new(2), 100), 100);
+        assert_eq!(order::<ZmodUnits<101>, _>(Zmod::new(10), 100), 4);
+        assert_eq!(order::<ZmodUnits<101>, _>(Zmod::new(1), 100), 1);
+        assert_eq!(order::<<Zmod<12> as Semiring>::Sum, _>(Zmod::new(8), 12), 3);
+    }
+
+    #[test]
+    fn logarithm() {
+        assert_eq!(baby_step_giant_step::<ZmodUnits<17>, _>(Zmod::new(3), Zmod::new(13), 16), Some(4));
+        assert_eq!(discrete_log::<ZmodUnits<17>, _>(Zmod::new(3), Zmod::new(13), 16), Some(4));
+        assert_eq!(discrete_log::<Units, _>(Z::new(2), Z::new(12345), 1000002).map(|x| monoid::pow::<Units, _>(Z::new(2), x)), Some(Z::new(12345)));
+        // 10 generates {1, 10, 100, 91}
+        assert_eq!(discrete_log::<ZmodUnits<101>, _>(Zmod::new(10), Zmod::new(91), 100), Some(3));
+        assert_eq!(discrete_log::<ZmodUnits<101>, _>(Zmod::new(10), Zmod::new(2), 100), None);
+        // the units of composite moduli, 2 generates the 6 units modulo 9 and the 20 units modulo 25
+        assert_eq!(discrete_log::<ZmodUnits<9>, _>(Zmod::new(2), Zmod::new(7), 6), Some(4));
+        assert_eq!(discrete_log::<ZmodUnits<9>, _>(Zmod::new(4), Zmod::new(2), 6), None);
+        for x in 0..20 {
+            let target = monoid::pow::<ZmodUnits<25>, _>(Zmod::new(2), x);
+            assert_eq!(discrete_log::<ZmodUnits<25>, _>(Zmod::new(2), target, 20), Some(x));
+        }
+        type Gf = GaloisField<2, 8>;
+        let generator = Gf::primitive_element().unwrap();
+        for index in [1, 2, 3, 0x53, 0xca, 0xff] {
+            let element = Gf::from_index(index);
+            assert_eq!(discrete_log::<Multi<Gf>, _>(generator, element, 255), element.log());
+        }
+    }
+
+    #[test]
+    fn primitive_roots() {
+        assert_eq!(primitive_root_mod::<7>(), Some(Zmod::new(3)));
+        assert_eq!(primitive_root_mod::<9>(), Some(Zmod::new(2)));
+        assert_eq!(primitive_root_mod::<2>(), Some(Zmod::new(1)));
+        assert_eq!(primitive_root_mod::<8>(), None);
+        assert_eq!(primitive_root_mod::<1000003>(), Some(Z::new(2)));
+        type Gf = GaloisField<3, 4>;
+        let root = primitive_root::<Multi<Gf>, _>((1..81).map(Gf::from_index), 80).unwrap();
+        assert_eq!(order::<Multi<Gf>, _>(root, 80), 80);
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use crate::structures::ring_like::{one, Semiring, SemiringWithOne, zero};
+
+// `re + eps·ε` with `ε² = 0`, so `f(x + ε) = f(x) + f'(x)·ε` for any polynomial-like `f`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Dual<T> {
+    pub re: T,
+    pub eps: T,
+}
+
+impl<T> Dual<T> {
+    pub fn new(re: T, eps: T) -> Dual<T> { Dual { re, eps } }
+}
+
+impl<T: Semiring> Dual<T> {
+    pub fn constant(value: T) -> Dual<T> { Dual::new(value, zero()) }
+}
+
+impl<T: SemiringWithOne> Dual<T> {
+    pub fn variable(value: T) -> Dual<T> { Dual::new(value, one()) }
+}
+
+pub fn derivative<T: SemiringWithOne>(f: impl FnOnce(Dual<T>) -> Dual<T>, at: T) -> T {
+    f(Dual::variable(at)).eps
+}
+
+impl<T: Display> Display for Dual<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+{}ε", self.re, self.eps)
+    }
+}
+
+
+impl<T: Add<Output=T>> Add for Dual<T> {
+    type Output = Dual<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Dual<T> {
+    type Output = Dual<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for Dual<T> {
+    type Output = Dual<T>;
+
+    fn neg(self) -> Self::Output {
+        Dual::new(-self.re, -self.eps)
+    }
+}
+
+impl<T: Add<Output=T> + Mul<Output=T> + Clone> Mul for Dual<T> {
+    type Output = Dual<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dual::new(self.re.clone() * rhs.re.clone(), self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
@@ -1,11 +1,13 @@
-use std::fmt::Display;
 use crate::matrix::{FiniteMatrix, Matrix};
 use crate::matrix::finite::Shape;
 use crate::matrix::Matrix::Finite;
 use crate::power::unsigned::pow;
+use crate::implementations::instances::MatrixMultiplication;
+use crate::power::instance;
+use crate::structures::instances::{SemiringWithOneInstance, WithIdentityElementInstance};
 use crate::structures::ring_like::{multi, one, RingWithOne, zero};
 
-pub fn fibonacci<T: RingWithOne + Clone + Display>(index: u64) -> T {
+pub fn fibonacci<T: RingWithOne + Clone>(index: u64) -> T {
     let base: Matrix<T> = Finite(FiniteMatrix::from_iter(
         Shape { height: 1, width: 2 },
         vec![zero(), one()].into_iter()
@@ -18,3 +20,10 @@ pub fn fibonacci<T: RingWithOne + Clone + Display>(index: u64) -> T {
     let matrix = multi(base, multiplier);
     matrix.get((0, 0)).to_owned()
 }
+
+pub fn fibonacci_in<T: Clone, S: SemiringWithOneInstance<T> + Clone>(ring: &S, index: u64) -> T {
+    let (zero, one) = (ring.sum().identity(), ring.multi().identity());
+    let step = FiniteMatrix::from_iter(Shape { height: 2, width: 2 }, vec![zero, one.clone(), one.clone(), one].into_iter());
+    let matrices = MatrixMultiplication::new(ring.clone(), 2);
+    instance::pow(&matrices, step, index)[(1, 0)].clone()
+}
@@ -0,0 +1,409 @@
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::rc::Rc;
+use crate::modular::Zmod;
+
+// fields up to this order multiply and invert through log/antilog tables
+const TABLE_LIMIT: u64 = 1 << 16;
+
+// lower coefficients `c₀, …, c_{N-1}` of a monic irreducible `xᴺ + c_{N-1}·x^{N-1} + … + c₀` over `Zmod<P>`
+pub trait IrreduciblePolynomial<const P: u64, const N: usize>: 'static {
+    fn coefficients() -> [u64; N];
+}
+
+// the irreducible polynomial whose coefficients `c_{N-1} … c₀` form the smallest base-`P` number
+pub struct Lowest;
+
+impl<const P: u64, const N: usize> IrreduciblePolynomial<P, N> for Lowest {
+    fn coefficients() -> [u64; N] {
+        let index = (0u128..).find(|&index| {
+            let lower = digits::<P>(index, N);
+            N == 1 || (lower[0] != Zmod::new(0) && is_irreducible(&monic(lower)))
+        }).unwrap();
+        digits::<P>(index, N).into_iter().map(Zmod::value).collect::<Vec<_>>().try_into().unwrap()
+    }
+}
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum GaloisFieldError {
+    CompositeCharacteristic,
+    ReducibleModulus,
+}
+
+impl Display for GaloisFieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GaloisFieldError::CompositeCharacteristic => write!(f, "the characteristic of a Galois field must be prime"),
+            GaloisFieldError::ReducibleModulus => write!(f, "the modulus of a Galois field must be irreducible"),
+        }
+    }
+}
+
+impl std::error::Error for GaloisFieldError {}
+
+
+// `GF(Pᴺ)` as polynomials over `Zmod<P>` modulo the irreducible `M`, `P` must be prime
+// invalid parameters are reported by `try_new` and `validate`, arithmetic with them panics
+pub struct GaloisField<const P: u64, const N: usize, M = Lowest> {
+    coefficients: [Zmod<P>; N],
+    modulus: PhantomData<M>,
+}
+
+struct Tables {
+    exp: Vec<u64>,
+    log: Vec<u64>,
+}
+
+struct Context {
+    modulus: Vec<u64>,
+    tables: Option<Tables>,
+}
+
+thread_local! {
+    static CONTEXTS: RefCell<HashMap<TypeId, Rc<Context>>> = RefCell::default();
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> GaloisField<P, N, M> {
+    pub fn new(coefficients: [u64; N]) -> Self {
+        Self::from_coefficients(coefficients.map(Zmod::new))
+    }
+
+    pub fn try_new(coefficients: [u64; N]) -> Result<Self, GaloisFieldError> {
+        Self::validate().map(|()| Self::new(coefficients))
+    }
+
+    // irreducibility of the modulus also guarantees a primitive element, i.e. a cyclic multiplicative group
+    pub fn validate() -> Result<(), GaloisFieldError> {
+        if !Zmod::<P>::IS_PRIME {
+            return Err(GaloisFieldError::CompositeCharacteristic);
+        }
+        let modulus = monic(M::coefficients().map(Zmod::<P>::new).to_vec());
+        if N > 1 && !is_irreducible(&modulus) {
+            return Err(GaloisFieldError::ReducibleModulus);
+        }
+        Ok(())
+    }
+
+    pub fn from_coefficients(coefficients: [Zmod<P>; N]) -> Self {
+        GaloisField { coefficients, modulus: PhantomData }
+    }
+
+    pub fn constant(value: u64) -> Self {
+        let mut coefficients = [Zmod::new(0); N];
+        coefficients[0] = Zmod::new(value);
+        Self::from_coefficients(coefficients)
+    }
+
+    pub fn coefficients(&self) -> [Zmod<P>; N] { self.coefficients }
+
+    // `Σ cᵢ·Pⁱ`, e.g. the usual byte encoding of `GF(2⁸)`
+    pub fn from_index(index: u64) -> Self {
+        Self::from_coefficients(digits::<P>(index as u128, N).try_into().unwrap())
+    }
+
+    pub fn index(&self) -> u64 {
+        self.coefficients.iter().rev().fold(0, |index, coefficient| index * P + coefficient.value())
+    }
+
+    pub fn order() -> Option<u64> { P.checked_pow(N as u32) }
+
+    pub fn modulus() -> [u64; N] {
+        Self::context().modulus.clone().try_into().unwrap()
+    }
+
+    pub fn is_zero(&self) -> bool { self.coefficients.iter().all(|c| c.value() == 0) }
+
+    fn context() -> Rc<Context> {
+        let key = TypeId::of::<Self>();
+        if let Some(context) = CONTEXTS.with(|contexts| contexts.borrow().get(&key).cloned()) {
+            return context;
+        }
+        if let Err(error) = Self::validate() {
+            panic!("{error}");
+        }
+        let modulus = M::coefficients().map(|c| c % P).to_vec();
+        let tables = Self::order().filter(|&order| order <= TABLE_LIMIT).map(|order| Self::tables(order, &modulus));
+        let context = Rc::new(Context { modulus, tables });
+        CONTEXTS.with(|contexts| contexts.borrow_mut().insert(key, context.clone()));
+        context
+    }
+
+    fn tables(order: u64, modulus: &[u64]) -> Tables {
+        let modulus = monic(modulus.iter().copied().map(Zmod::new).collect());
+        let multiply = |a: u64, b: u64| {
+            let product = multiply_modulo(&digits::<P>(a as u128, N), &digits::<P>(b as u128, N), &modulus);
+            Self::from_coefficients(extend(product, N).try_into().unwrap()).index()
+        };
+        let group_order = order - 1;
+        for generator in 1..order {
+            let mut exp = Vec::with_capacity(2 * group_order as usize);
+            let mut power = 1;
+            for _ in 0..group_order {
+                if power == 1 && !exp.is_empty() {
+                    break;
+                }
+                exp.push(power);
+                power = multiply(power, generator);
+            }
+            if exp.len() as u64 == group_order {
+                let mut log = vec![0; order as usize];
+                for (exponent, &power) in exp.iter().enumerate() {
+                    log[power as usize] = exponent as u64;
+                }
+                exp.extend_from_within(..);
+                return Tables { exp, log };
+            }
+        }
+        unreachable!("The multiplicative group of a finite field is cyclic and the modulus is validated")
+    }
+
+    // some generator of the multiplicative group, available for fields with log/antilog tables
+    pub fn primitive_element() -> Option<Self> {
+        Self::context().tables.as_ref().map(|tables| Self::from_index(tables.exp[1 % tables.exp.len()]))
+    }
+
+    // discrete logarithm to the base `primitive_element()`, `None` for zero or fields without tables
+    pub fn log(&self) -> Option<u64> {
+        match &Self::context().tables {
+            Some(tables) if !self.is_zero() => Some(tables.log[self.index() as usize]),
+            _ => None,
+        }
+    }
+
+    pub fn antilog(exponent: u64) -> Option<Self> {
+        Self::context().tables.as_ref().map(|tables| {
+            let group_order = tables.exp.len() as u64 / 2;
+            Self::from_index(tables.exp[(exponent % group_order) as usize])
+        })
+    }
+
+    pub fn try_inverse(self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let context = Self::context();
+        if let Some(tables) = &context.tables {
+            let group_order = tables.exp.len() as u64 / 2;
+            let log = tables.log[self.index() as usize];
+            return Some(Self::from_index(tables.exp[((group_order - log) % group_order) as usize]));
+        }
+        let modulus = monic(context.modulus.iter().copied().map(Zmod::new).collect());
+        let (mut r0, mut r1) = (modulus, trim(self.coefficients.to_vec()));
+        let (mut s0, mut s1) = (vec![], vec![Zmod::new(1)]);
+        while !r1.is_empty() {
+            let (quotient, remainder) = divide(&r0, &r1);
+            (r0, r1) = (r1, remainder);
+            let next = subtract(&s0, &multiply(&quotient, &s1));
+            (s0, s1) = (s1, next);
+        }
+        let scale = r0[0].try_inverse().unwrap();
+        let inverse = s0.into_iter().map(|c| c * scale).collect();
+        Some(Self::from_coefficients(extend(inverse, N).try_into().unwrap()))
+    }
+}
+
+
+fn digits<const P: u64>(mut index: u128, count: usize) -> Vec<Zmod<P>> {
+    (0..count).map(|_| {
+        let digit = Zmod::new((index % P as u128) as u64);
+        index /= P as u128;
+        digit
+    }).collect()
+}
+
+fn trim<const P: u64>(mut polynomial: Vec<Zmod<P>>) -> Vec<Zmod<P>> {
+    while polynomial.last().is_some_and(|c| c.value() == 0) {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+fn extend<const P: u64>(mut polynomial: Vec<Zmod<P>>, length: usize) -> Vec<Zmod<P>> {
+    polynomial.resize(length, Zmod::new(0));
+    polynomial
+}
+
+fn monic<const P: u64>(mut lower: Vec<Zmod<P>>) -> Vec<Zmod<P>> {
+    lower.push(Zmod::new(1));
+    lower
+}
+
+fn subtract<const P: u64>(a: &[Zmod<P>], b: &[Zmod<P>]) -> Vec<Zmod<P>> {
+    let zero = Zmod::new(0);
+    let result = (0..a.len().max(b.len()))
+        .map(|i| *a.get(i).unwrap_or(&zero) - *b.get(i).unwrap_or(&zero))
+        .collect();
+    trim(result)
+}
+
+fn multiply<const P: u64>(a: &[Zmod<P>], b: &[Zmod<P>]) -> Vec<Zmod<P>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![Zmod::new(0); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            result[i + j] = result[i + j] + x * y;
+        }
+    }
+    trim(result)
+}
+
+// the divisor must be non-zero
+fn divide<const P: u64>(dividend: &[Zmod<P>], divisor: &[Zmod<P>]) -> (Vec<Zmod<P>>, Vec<Zmod<P>>) {
+    let mut remainder = trim(dividend.to_vec());
+    let divisor = trim(divisor.to_vec());
+    let leading_inverse = divisor.last().unwrap().try_inverse().unwrap();
+    if remainder.len() < divisor.len() {
+        return (vec![], remainder);
+    }
+    let mut quotient = vec![Zmod::new(0); remainder.len() - divisor.len() + 1];
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let factor = *remainder.last().unwrap() * leading_inverse;
+        quotient[shift] = factor;
+        for (i, &c) in divisor.iter().enumerate() {
+            remainder[shift + i] = remainder[shift + i] - factor * c;
+        }
+        remainder = trim(remainder);
+    }
+    (trim(quotient), remainder)
+}
+
+fn multiply_modulo<const P: u64>(a: &[Zmod<P>], b: &[Zmod<P>], modulus: &[Zmod<P>]) -> Vec<Zmod<P>> {
+    divide(&multiply(a, b), modulus).1
+}
+
+fn gcd<const P: u64>(a: &[Zmod<P>], b: &[Zmod<P>]) -> Vec<Zmod<P>> {
+    let (mut a, mut b) = (trim(a.to_vec()), trim(b.to_vec()));
+    while !b.is_empty() {
+        let remainder = divide(&a, &b).1;
+        (a, b) = (b, remainder);
+    }
+    a
+}
+
+// Rabin's test: `f` of degree `n` is irreducible iff `gcd(x^(Pⁱ) - x, f) = 1` for all `i ≤ n / 2`
+fn is_irreducible<const P: u64>(polynomial: &[Zmod<P>]) -> bool {
+    let degree = polynomial.len() - 1;
+    let x = vec![Zmod::new(0), Zmod::new(1)];
+    let mut power = x.clone();
+    for _ in 0..degree / 2 {
+        let (mut base, mut exponent, mut result) = (power, P, vec![Zmod::new(1)]);
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = multiply_modulo(&result, &base, polynomial);
+            }
+            base = multiply_modulo(&base, &base, polynomial);
+            exponent /= 2;
+        }
+        power = result;
+        if gcd(&subtract(&power, &x), polynomial).len() > 1 {
+            return false;
+        }
+    }
+    true
+}
+
+
+impl<const P: u64, const N: usize, M> Clone for GaloisField<P, N, M> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<const P: u64, const N: usize, M> Copy for GaloisField<P, N, M> {}
+
+impl<const P: u64, const N: usize, M> PartialEq for GaloisField<P, N, M> {
+    fn eq(&self, other: &Self) -> bool { self.coefficients == other.coefficients }
+}
+
+impl<const P: u64, const N: usize, M> Eq for GaloisField<P, N, M> {}
+
+impl<const P: u64, const N: usize, M> Hash for GaloisField<P, N, M> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.coefficients.hash(state) }
+}
+
+impl<const P: u64, const N: usize, M> Debug for GaloisField<P, N, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GaloisField").field(&self.coefficients).finish()
+    }
+}
+
+// as a polynomial in `x`, e.g. `x^3+2x+1`
+impl<const P: u64, const N: usize, M> Display for GaloisField<P, N, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let terms = self.coefficients.iter().enumerate().rev()
+            .filter(|(_, c)| c.value() != 0)
+            .map(|(power, c)| match (power, c.value()) {
+                (0, c) => format!("{c}"),
+                (1, 1) => "x".to_owned(),
+                (1, c) => format!("{c}x"),
+                (power, 1) => format!("x^{power}"),
+                (power, c) => format!("{c}x^{power}"),
+            })
+            .collect::<Vec<_>>();
+        if terms.is_empty() { write!(f, "0") } else { write!(f, "{}", terms.join("+")) }
+    }
+}
+
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Add for GaloisField<P, N, M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut coefficients = self.coefficients;
+        for (c, r) in coefficients.iter_mut().zip(rhs.coefficients) {
+            *c = *c + r;
+        }
+        Self::from_coefficients(coefficients)
+    }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Sub for GaloisField<P, N, M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Neg for GaloisField<P, N, M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_coefficients(self.coefficients.map(Neg::neg))
+    }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Mul for GaloisField<P, N, M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let context = Self::context();
+        if let Some(tables) = &context.tables {
+            return if self.is_zero() || rhs.is_zero() {
+                Self::constant(0)
+            } else {
+                let log = tables.log[self.index() as usize] + tables.log[rhs.index() as usize];
+                Self::from_index(tables.exp[log as usize])
+            };
+        }
+        let modulus = monic(context.modulus.iter().copied().map(Zmod::new).collect());
+        let product = multiply_modulo(&self.coefficients, &rhs.coefficients, &modulus);
+        Self::from_coefficients(extend(product, N).try_into().unwrap())
+    }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Div for GaloisField<P, N, M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let inverse = rhs.try_inverse().expect("Division by zero");
+        Mul::mul(self, inverse)
+    }
+}
@@ -3,6 +3,20 @@ mod helpers;
 pub mod fractionals;
 pub mod matrix;
 pub mod finite_matrix;
+pub mod quaternion;
+pub mod dual;
+pub mod modular;
+pub mod galois;
+pub mod polynomial;
+pub mod permutation;
+pub mod instances;
+pub mod homomorphism;
+pub mod product;
+pub mod module;
+pub mod monoid_algebra;
+pub mod lattice;
+pub mod domains;
+mod convolution;
 
 
 #[cfg(test)]
@@ -25,7 +39,6 @@ mod tests {
         supplier_semiring::<u16>();
         supplier_semiring::<u32>();
         supplier_semiring::<u64>();
-        #[cfg(has_i128)]
         supplier_semiring::<u128>();
         supplier_semiring::<usize>();
         supplier_semiring::<BigUint>();
@@ -36,7 +49,6 @@ mod tests {
         supplier_ring::<i16>();
         supplier_ring::<i32>();
         supplier_ring::<i64>();
-        #[cfg(has_i128)]
         supplier_ring::<i128>();
         supplier_ring::<isize>();
         supplier_ring::<BigInt>();
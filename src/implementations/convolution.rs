@@ -0,0 +1,108 @@
+use std::f64::consts::TAU;
+use std::num::Wrapping;
+use num::{BigInt, BigUint, Complex};
+use num::rational::Ratio;
+use crate::dual::Dual;
+use crate::galois::{GaloisField, IrreduciblePolynomial};
+use crate::matrix::Matrix;
+use crate::modular::{Zmod, Zp};
+use crate::polynomial::convolution::{Convolution, schoolbook_or_karatsuba, schoolbook_or_toom3, karatsuba_or_transform};
+use crate::polynomial::Polynomial;
+use crate::polynomial::transform::RootOfUnity;
+use crate::power::unsigned::pow;
+use crate::quaternion::Quaternion;
+use crate::structures::ring_like::{CommutativeRing, one, Semiring};
+
+macro_rules! schoolbook {
+    ($($t:ty),+) => { $(impl Convolution for $t {})+ };
+}
+
+macro_rules! karatsuba {
+    ($($t:ty),+) => {$(
+impl Convolution for $t {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { schoolbook_or_karatsuba(a, b) }
+}
+    )+};
+}
+
+macro_rules! toom3 {
+    ($($t:ty),+) => {$(
+impl Convolution for $t {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { schoolbook_or_toom3(a, b) }
+}
+    )+};
+}
+
+// no subtraction
+schoolbook!(u8, u16, u32, u64, u128, usize, BigUint);
+schoolbook!(Complex<u8>, Complex<u16>, Complex<u32>, Complex<u64>, Complex<u128>, Complex<usize>, Complex<BigUint>);
+schoolbook!(Ratio<u8>, Ratio<u16>, Ratio<u32>, Ratio<u64>, Ratio<u128>, Ratio<usize>, Ratio<BigUint>);
+
+// floats would lose precision in the Toom-3 interpolation
+karatsuba!(f32, f64);
+karatsuba!(Complex<i8>, Complex<i16>, Complex<i32>, Complex<i64>, Complex<i128>, Complex<isize>, Complex<BigInt>);
+karatsuba!(Ratio<i8>, Ratio<i16>, Ratio<i32>, Ratio<i64>, Ratio<i128>, Ratio<isize>);
+// intermediates of Toom-3 and Karatsuba overflow even if the product fits, the schoolbook method overflows like the scalar sums,
+// `Wrapping` asks for arithmetic modulo `2ⁿ`, where Karatsuba is exact
+schoolbook!(i8, i16, i32, i64, i128, isize);
+karatsuba!(Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>);
+karatsuba!(Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>);
+
+toom3!(BigInt, Ratio<BigInt>);
+
+// non-commutative coefficients
+impl<T: Semiring + Clone> Convolution for Matrix<T> {}
+
+impl<T: CommutativeRing + Clone> Convolution for Quaternion<T> {}
+
+impl<T: Semiring + Clone> Convolution for Dual<T> {}
+
+impl<T: Convolution + PartialEq> Convolution for Polynomial<T> {}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Convolution for GaloisField<P, N, M> {}
+
+
+// roots of unity of order `2ᵉ` are only looked for modulo primes `k·2ᵉ + 1`
+impl<const M: u64> RootOfUnity for Zmod<M> {
+    fn root_of_unity(order: usize) -> Option<Self> {
+        let order = order as u64;
+        if !Zmod::<M>::IS_PRIME || !order.is_power_of_two() || !(M - 1).is_multiple_of(order) {
+            return None;
+        }
+        if order == 1 {
+            return Some(one());
+        }
+        // every quadratic non-residue gives one this way, so the search ends below `M`
+        (2..M)
+            .map(|candidate| pow(Zmod::new(candidate), (M - 1) / order))
+            .find(|&root| pow(root, order / 2) == -one::<Zmod<M>>())
+    }
+}
+
+impl<const M: u64> Convolution for Zmod<M> {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { karatsuba_or_transform(a, b) }
+}
+
+impl<const P: u64> RootOfUnity for Zp<P> {
+    fn root_of_unity(order: usize) -> Option<Self> { Zmod::<P>::root_of_unity(order).map(Zp::from_residue) }
+}
+
+impl<const P: u64> Convolution for Zp<P> {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { karatsuba_or_transform(a, b) }
+}
+
+macro_rules! complex_transform {
+    ($($t:ty),+) => {$(
+impl RootOfUnity for Complex<$t> {
+    fn root_of_unity(order: usize) -> Option<Self> {
+        Some(Complex::from_polar(1.0, (TAU / order as f64) as $t))
+    }
+}
+
+impl Convolution for Complex<$t> {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { karatsuba_or_transform(a, b) }
+}
+    )+};
+}
+
+complex_transform!(f32, f64);
@@ -0,0 +1,186 @@
+use num::{BigInt, BigUint, Complex, Float, Integer, Num, One, Zero};
+use num::rational::Ratio;
+use crate::galois::{GaloisField, IrreduciblePolynomial};
+use crate::implementations::helpers::multi::AssociativeCommutativeMulti;
+use crate::modular::{Zmod, Zp};
+use crate::structures::basics::TryInvertible;
+use crate::structures::ring_like::{Gcd, NoZeroDivisors, PrincipalIdeals, try_inverse, UniqueFactorization, UnitDetection};
+
+// the fixed-width signed integers model ℤ, overflow aside
+macro_rules! integers {
+    ($($t:ty),+) => {$(
+impl NoZeroDivisors for AssociativeCommutativeMulti<$t> {}
+
+impl UniqueFactorization for AssociativeCommutativeMulti<$t> {}
+
+impl PrincipalIdeals for AssociativeCommutativeMulti<$t> {}
+
+impl Gcd<$t> for AssociativeCommutativeMulti<$t> {
+    fn gcd(operand1: $t, operand2: $t) -> $t { operand1.gcd(&operand2) }
+}
+
+impl TryInvertible<$t> for AssociativeCommutativeMulti<$t> {
+    fn try_inverse(operand: $t) -> Option<$t> {
+        (operand.is_one() || operand == -<$t>::one()).then_some(operand)
+    }
+}
+    )+};
+}
+
+integers!(i8, i16, i32, i64, i128, isize, BigInt);
+
+macro_rules! naturals {
+    ($($t:ty),+) => {$(
+impl TryInvertible<$t> for AssociativeCommutativeMulti<$t> {
+    fn try_inverse(operand: $t) -> Option<$t> { operand.is_one().then_some(operand) }
+}
+    )+};
+}
+
+naturals!(u8, u16, u32, u64, u128, usize, BigUint);
+
+
+// every field is a principal ideal domain whose non-zero elements are units
+macro_rules! field_markers {
+    ($t:ty $(, $p:tt: $($bound:tt)+)?) => {
+impl$(<$p: $($bound)+>)? NoZeroDivisors for AssociativeCommutativeMulti<$t> {}
+
+impl$(<$p: $($bound)+>)? UniqueFactorization for AssociativeCommutativeMulti<$t> {}
+
+impl$(<$p: $($bound)+>)? PrincipalIdeals for AssociativeCommutativeMulti<$t> {}
+
+impl$(<$p: $($bound)+>)? Gcd<$t> for AssociativeCommutativeMulti<$t> {
+    fn gcd(operand1: $t, operand2: $t) -> $t {
+        if operand1.is_zero() && operand2.is_zero() { <$t>::zero() } else { <$t>::one() }
+    }
+}
+    };
+}
+
+field_markers!(f32);
+field_markers!(f64);
+field_markers!(Ratio<T>, T: Integer + Clone);
+field_markers!(Complex<T>, T: Float);
+
+impl TryInvertible<f32> for AssociativeCommutativeMulti<f32> {
+    fn try_inverse(operand: f32) -> Option<f32> { (operand != 0.0).then(|| operand.recip()) }
+}
+
+impl TryInvertible<f64> for AssociativeCommutativeMulti<f64> {
+    fn try_inverse(operand: f64) -> Option<f64> { (operand != 0.0).then(|| operand.recip()) }
+}
+
+impl<T: Integer + Clone> TryInvertible<Ratio<T>> for AssociativeCommutativeMulti<Ratio<T>> {
+    fn try_inverse(operand: Ratio<T>) -> Option<Ratio<T>> { (!operand.is_zero()).then(|| operand.recip()) }
+}
+
+// `z⁻¹ = z̄ / |z|²`, so Gaussian integers have the units `±1`, `±i`
+impl<T: Num + Clone + std::ops::Neg<Output=T> + UnitDetection> TryInvertible<Complex<T>> for AssociativeCommutativeMulti<Complex<T>> {
+    fn try_inverse(operand: Complex<T>) -> Option<Complex<T>> {
+        let scale = try_inverse(operand.norm_sqr())?;
+        Some(operand.conj().scale(scale))
+    }
+}
+
+impl<const M: u64> TryInvertible<Zmod<M>> for AssociativeCommutativeMulti<Zmod<M>> {
+    fn try_inverse(operand: Zmod<M>) -> Option<Zmod<M>> { operand.try_inverse() }
+}
+
+impl<const P: u64> NoZeroDivisors for AssociativeCommutativeMulti<Zp<P>> {}
+
+impl<const P: u64> UniqueFactorization for AssociativeCommutativeMulti<Zp<P>> {}
+
+impl<const P: u64> PrincipalIdeals for AssociativeCommutativeMulti<Zp<P>> {}
+
+impl<const P: u64> Gcd<Zp<P>> for AssociativeCommutativeMulti<Zp<P>> {
+    fn gcd(operand1: Zp<P>, operand2: Zp<P>) -> Zp<P> {
+        Zp::new(u64::from(operand1.value() != 0 || operand2.value() != 0))
+    }
+}
+
+impl<const P: u64> TryInvertible<Zp<P>> for AssociativeCommutativeMulti<Zp<P>> {
+    fn try_inverse(operand: Zp<P>) -> Option<Zp<P>> { operand.try_inverse() }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> NoZeroDivisors for AssociativeCommutativeMulti<GaloisField<P, N, M>> {}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> UniqueFactorization for AssociativeCommutativeMulti<GaloisField<P, N, M>> {}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> PrincipalIdeals for AssociativeCommutativeMulti<GaloisField<P, N, M>> {}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Gcd<GaloisField<P, N, M>> for AssociativeCommutativeMulti<GaloisField<P, N, M>> {
+    fn gcd(operand1: GaloisField<P, N, M>, operand2: GaloisField<P, N, M>) -> GaloisField<P, N, M> {
+        GaloisField::constant(u64::from(!operand1.is_zero() || !operand2.is_zero()))
+    }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> TryInvertible<GaloisField<P, N, M>> for AssociativeCommutativeMulti<GaloisField<P, N, M>> {
+    fn try_inverse(operand: GaloisField<P, N, M>) -> Option<GaloisField<P, N, M>> { operand.try_inverse() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigUint, Complex};
+    use num::rational::Ratio;
+    use crate::galois::GaloisField;
+    use crate::modular::{Zmod, Zp};
+    use crate::structures::ring_like::{gcd, GcdDomain, IntegralDomain, is_unit, is_zero, PrincipalIdealDomain, try_inverse, UniqueFactorizationDomain, UnitDetection};
+
+    fn supplier_integral_domain<T: IntegralDomain>() {}
+
+    fn supplier_gcd_domain<T: GcdDomain>() {}
+
+    fn supplier_ufd<T: UniqueFactorizationDomain>() {}
+
+    fn supplier_pid<T: PrincipalIdealDomain>() {}
+
+    fn supplier_unit_detection<T: UnitDetection>() {}
+
+    #[test]
+    fn hierarchy() {
+        supplier_pid::<i32>();
+        supplier_pid::<BigInt>();
+        supplier_pid::<Ratio<i64>>();
+        supplier_pid::<f64>();
+        supplier_ufd::<Complex<f32>>();
+        supplier_gcd_domain::<GaloisField<2, 8>>();
+        supplier_pid::<Zp<13>>();
+        supplier_integral_domain::<i8>();
+        supplier_unit_detection::<u16>();
+        supplier_unit_detection::<BigUint>();
+        supplier_unit_detection::<Zmod<12>>();
+        supplier_unit_detection::<Complex<i64>>();
+    }
+
+    #[test]
+    fn gcds() {
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(BigInt::from(0), BigInt::from(0)), BigInt::from(0));
+        assert_eq!(gcd(Ratio::new(1, 2), Ratio::from_integer(0)), Ratio::from_integer(1));
+        assert_eq!(gcd(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(try_inverse(-1i32), Some(-1));
+        assert_eq!(try_inverse(2i32), None);
+        assert_eq!(try_inverse(i8::MIN), None);
+        assert_eq!(try_inverse(1u8), Some(1));
+        assert_eq!(try_inverse(0.0f64), None);
+        assert_eq!(try_inverse(4.0f64), Some(0.25));
+        assert_eq!(try_inverse(Ratio::new(-2, 3)), Some(Ratio::new(-3, 2)));
+        assert_eq!(try_inverse(Zmod::<12>::new(5)), Some(Zmod::new(5)));
+        assert_eq!(try_inverse(Zmod::<12>::new(4)), None);
+        assert_eq!(try_inverse(Zp::<13>::new(4)), Some(Zp::new(10)));
+        assert_eq!(try_inverse(Zp::<13>::new(0)), None);
+        assert_eq!(try_inverse(Complex::new(0i64, -1)), Some(Complex::new(0, 1)));
+        assert_eq!(try_inverse(Complex::new(1i64, 1)), None);
+        assert_eq!(try_inverse(Complex::new(0.0, 2.0)), Some(Complex::new(0.0, -0.5)));
+        assert_eq!(try_inverse(GaloisField::<2, 8>::from_index(0)), None);
+        assert!(is_unit(&GaloisField::<2, 8>::from_index(0x53)));
+        assert!(!is_unit(&BigInt::from(7)));
+        assert!(is_zero(&Ratio::<i32>::from_integer(0)));
+        assert!(!is_zero(&Complex::new(0, 1)));
+    }
+}
@@ -0,0 +1,99 @@
+use std::marker::PhantomData;
+use crate::dual::Dual;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::ring_like::{Field, multi, one, plus, reciprocal, Semiring, unary_minus, zero};
+
+pub struct DualSum<T: Semiring>(PhantomData<T>);
+
+impl<T: Semiring> Commutative for DualSum<T> where <T as Semiring>::Sum: Commutative {}
+
+impl<T: Semiring> Associative for DualSum<T> where <T as Semiring>::Sum: Associative {}
+
+impl<T: Semiring> WithIdentityElement<Dual<T>> for DualSum<T> {
+    fn identity() -> Dual<T> {
+        Dual::new(zero(), zero())
+    }
+}
+
+impl<T: Semiring> Magma<Dual<T>> for DualSum<T> {
+    fn operation(operand1: Dual<T>, operand2: Dual<T>) -> Dual<T> {
+        Dual::new(plus(operand1.re, operand2.re), plus(operand1.eps, operand2.eps))
+    }
+}
+
+impl<T: Semiring> Invertible<Dual<T>> for DualSum<T> where T::Sum: Invertible<T> {
+    fn inverse(operand: Dual<T>) -> Dual<T> {
+        Dual::new(T::Sum::inverse(operand.re), T::Sum::inverse(operand.eps))
+    }
+}
+
+
+pub struct DualMul<T: Semiring>(PhantomData<T>);
+
+impl<T: Semiring + Clone> Semiring for Dual<T> {
+    type Sum = DualSum<T>;
+    type Multi = DualMul<T>;
+}
+
+impl<T: Semiring> Commutative for DualMul<T> where <T as Semiring>::Multi: Commutative {}
+
+impl<T: Semiring> Associative for DualMul<T> where <T as Semiring>::Multi: Associative {}
+
+impl<T: Semiring> WithIdentityElement<Dual<T>> for DualMul<T> where T::Multi: WithIdentityElement<T> {
+    fn identity() -> Dual<T> {
+        Dual::new(one(), zero())
+    }
+}
+
+impl<T: Semiring + Clone> Magma<Dual<T>> for DualMul<T> {
+    fn operation(operand1: Dual<T>, operand2: Dual<T>) -> Dual<T> {
+        let eps = plus(multi(operand1.re.clone(), operand2.eps), multi(operand1.eps, operand2.re.clone()));
+        Dual::new(multi(operand1.re, operand2.re), eps)
+    }
+}
+
+// only units `a + bε` with `a ≠ 0` have inverses, dividing by the rest is implementation defined
+impl<T: Field + Clone> Invertible<Dual<T>> for DualMul<T> {
+    fn inverse(operand: Dual<T>) -> Dual<T> {
+        let re = reciprocal(operand.re);
+        let eps = unary_minus(multi(operand.eps, multi(re.clone(), re.clone())));
+        Dual::new(re, eps)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::dual::{derivative, Dual};
+    use crate::matrix;
+    use crate::power::{signed, unsigned};
+    use crate::structures::ring_like::{CommutativeRingWithOne, div, Field, multi, plus};
+
+    fn supplier_ring<T: CommutativeRingWithOne>() {}
+
+    fn supplier_field<T: Field>() {}
+
+    #[test]
+    fn derivatives() {
+        supplier_ring::<Dual<i64>>();
+        supplier_field::<Dual<f64>>();
+        assert_eq!(derivative(|x| unsigned::pow(x, 3), 2), 12);
+        assert_eq!(derivative(|x| plus(multi(x, x), x), 5), 11);
+        assert_eq!(derivative(|x| signed::pow(x, -1), 2.0), -0.25);
+        assert_eq!(derivative(|x| div(Dual::constant(1.0), plus(x, Dual::constant(1.0))), 1.0), -0.25);
+    }
+
+    #[test]
+    fn matrix_directional_derivative() {
+        // d/dt (A + tB)² = AB + BA
+        let a = [1, 2, 3, 4];
+        let b = [0, 1, 1, 0];
+        let entry = |index: usize| Dual::new(a[index], b[index]);
+        let m = matrix![entry(0), entry(1); entry(2), entry(3)];
+        let square = multi(m.clone(), m);
+        assert_eq!(square.get((0, 0)), Dual::new(7, 5));
+        assert_eq!(square.get((0, 1)), Dual::new(10, 5));
+        assert_eq!(square.get((1, 0)), Dual::new(15, 5));
+        assert_eq!(square.get((1, 1)), Dual::new(22, 5));
+    }
+}
@@ -14,7 +14,7 @@ impl<T: Semiring> Magma<FiniteMatrix<T>> for FiniteMatrixSum<T> {
     fn operation(operand1: FiniteMatrix<T>, operand2: FiniteMatrix<T>) -> FiniteMatrix<T> {
         let shape = operand1.shape();
         assert_eq!(shape, operand2.shape());
-        FiniteMatrix::from_iter(shape, operand1.into_iter().zip(operand2.into_iter()).map(|(x, y)| plus(x, y)))
+        FiniteMatrix::from_iter(shape, operand1.into_iter().zip(operand2).map(|(x, y)| plus(x, y)))
     }
 }
 
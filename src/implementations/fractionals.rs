@@ -22,3 +22,40 @@ impls!(f32, 1.0);
 impls!(f64, 1.0);
 impls!(Ratio<T>, Ratio::<T>::one(), T, Integer);
 impls!(Complex<T>, Complex::<T>::one(), T, Num + Field);
+
+
+#[cfg(test)]
+mod tests {
+    use num::Complex;
+    use num::rational::Ratio;
+    use crate::modular::Zmod;
+    use crate::power::signed::checked_pow;
+    use crate::quaternion::Quaternion;
+    use crate::structures::ring_like::{DivisionByZero, try_div, try_reciprocal};
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(try_reciprocal(0.0f64), Err(DivisionByZero));
+        assert_eq!(try_reciprocal(-0.0f32), Err(DivisionByZero));
+        assert_eq!(try_reciprocal(Ratio::new(2, 5)), Ok(Ratio::new(5, 2)));
+        assert_eq!(try_div(Ratio::from_integer(1), Ratio::from_integer(0)), Err(DivisionByZero));
+        assert_eq!(try_div(Complex::new(0.0, 2.0), Complex::new(0.0, 1.0)), Ok(Complex::new(2.0, 0.0)));
+        assert_eq!(try_reciprocal(Quaternion::new(0.0, 0.0, 0.0, 0.0)), Err(DivisionByZero));
+        // rings with zero divisors fail for every non-unit, not only for zero
+        assert_eq!(try_reciprocal(Zmod::<6>::new(2)), Err(DivisionByZero));
+        assert_eq!(try_div(Zmod::<6>::new(1), Zmod::new(5)), Ok(Zmod::new(5)));
+        assert_eq!(DivisionByZero.to_string(), "Division by zero");
+    }
+
+    #[test]
+    fn checked_powers() {
+        assert_eq!(checked_pow(Ratio::from_integer(0), -1), Err(DivisionByZero));
+        assert_eq!(checked_pow(0.0, 0), Ok(1.0));
+        assert_eq!(checked_pow(0.0, 3), Ok(0.0));
+        assert_eq!(checked_pow(Ratio::new(2, 3), -2), Ok(Ratio::new(9, 4)));
+        assert_eq!(checked_pow(2.0, i64::MIN), Ok(0.0));
+        assert_eq!(checked_pow(Zmod::<6>::new(3), -1), Err(DivisionByZero));
+        assert_eq!(checked_pow(Zmod::<6>::new(5), -3), Ok(Zmod::new(5)));
+        assert_eq!(checked_pow(-1, i64::MIN), Ok(1));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::galois::{GaloisField, IrreduciblePolynomial};
+use crate::implementations::helpers::multi::AssociativeCommutativeMulti;
+use crate::implementations::helpers::sum::AssociativeCommutativeSum;
+use crate::structures::basics::{Invertible, WithIdentityElement};
+use crate::structures::ring_like::Semiring;
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> WithIdentityElement<GaloisField<P, N, M>>
+for AssociativeCommutativeSum<GaloisField<P, N, M>> {
+    fn identity() -> GaloisField<P, N, M> { GaloisField::constant(0) }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> WithIdentityElement<GaloisField<P, N, M>>
+for AssociativeCommutativeMulti<GaloisField<P, N, M>> {
+    fn identity() -> GaloisField<P, N, M> { GaloisField::constant(1) }
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Semiring for GaloisField<P, N, M> {
+    type Sum = AssociativeCommutativeSum<GaloisField<P, N, M>>;
+    type Multi = AssociativeCommutativeMulti<GaloisField<P, N, M>>;
+}
+
+impl<const P: u64, const N: usize, M: IrreduciblePolynomial<P, N>> Invertible<GaloisField<P, N, M>>
+for AssociativeCommutativeMulti<GaloisField<P, N, M>> {
+    fn inverse(operand: GaloisField<P, N, M>) -> GaloisField<P, N, M> { GaloisField::constant(1) / operand }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::galois::{GaloisField, GaloisFieldError, IrreduciblePolynomial};
+    use crate::power::{signed, unsigned};
+    use crate::structures::ring_like::{div, Field, multi, one, plus, reciprocal, zero};
+
+    fn supplier_field<T: Field>() {}
+
+    struct Aes;
+
+    impl IrreduciblePolynomial<2, 8> for Aes {
+        fn coefficients() -> [u64; 8] { [1, 1, 0, 1, 1, 0, 0, 0] }
+    }
+
+    type Gf256 = GaloisField<2, 8, Aes>;
+
+    #[test]
+    fn lowest_irreducible() {
+        assert_eq!(GaloisField::<2, 8>::modulus(), Aes::coefficients());
+        assert_eq!(GaloisField::<2, 2>::modulus(), [1, 1]);
+        assert_eq!(GaloisField::<3, 2>::modulus(), [1, 0]);
+        assert_eq!(GaloisField::<5, 1>::modulus(), [0]);
+    }
+
+    struct Reducible;
+
+    impl IrreduciblePolynomial<2, 2> for Reducible {
+        fn coefficients() -> [u64; 2] { [1, 0] }
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert_eq!(Gf256::validate(), Ok(()));
+        assert_eq!(Gf256::try_new([1, 0, 0, 0, 0, 0, 0, 0]), Ok(one()));
+        assert_eq!(GaloisField::<2, 2, Reducible>::try_new([1, 1]), Err(GaloisFieldError::ReducibleModulus));
+        assert_eq!(GaloisField::<4, 2>::validate(), Err(GaloisFieldError::CompositeCharacteristic));
+        let result = std::panic::catch_unwind(|| GaloisField::<2, 2, Reducible>::new([1, 1]) * GaloisField::new([0, 1]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn byte_field() {
+        supplier_field::<Gf256>();
+        let x = |index| Gf256::from_index(index);
+        assert_eq!(plus(x(0x57), x(0x83)), x(0xd4));
+        assert_eq!(multi(x(0x57), x(0x83)), x(0xc1));
+        assert_eq!(multi(x(0x53), x(0xca)), one());
+        assert_eq!(reciprocal(x(0x53)), x(0xca));
+        assert_eq!(signed::pow(x(0x53), -1), x(0xca));
+        assert_eq!(unsigned::pow(x(0x03), 255), one());
+        let generator = Gf256::primitive_element().unwrap();
+        assert_eq!(generator.log(), Some(1));
+        assert_eq!(x(0xd4).log().and_then(Gf256::antilog), Some(x(0xd4)));
+        assert_eq!(zero::<Gf256>().log(), None);
+        assert_eq!(format!("{}", x(0x0b)), "x^3+x+1");
+    }
+
+    #[test]
+    fn without_tables() {
+        // GF(3¹¹) is too large for log tables
+        type F = GaloisField<3, 11>;
+        assert_eq!(F::primitive_element(), None);
+        let a = F::new([1, 2, 0, 0, 1, 0, 2, 0, 0, 1, 2]);
+        let b = F::new([0, 1, 1, 1, 0, 0, 0, 0, 2, 0, 0]);
+        assert_eq!(multi(div(a, b), b), a);
+        assert_eq!(multi(a, reciprocal(a)), one());
+        // Frobenius map `a ↦ a³` is additive
+        let cube = |x: F| unsigned::pow(x, 3);
+        assert_eq!(cube(plus(a, b)), plus(cube(a), cube(b)));
+        assert_eq!(unsigned::pow(a, 177146), one());
+    }
+}
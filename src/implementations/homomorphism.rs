@@ -0,0 +1,95 @@
+use num::{BigInt, Integer, ToPrimitive};
+use num::rational::Ratio;
+use crate::matrix::Matrix;
+use crate::modular::Zmod;
+use crate::polynomial::Polynomial;
+use crate::structures::homomorphism::Homomorphism;
+use crate::structures::ring_like::Semiring;
+
+// `x ↦ x mod M`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct Reduction<const M: u64>;
+
+impl<const M: u64> Homomorphism<BigInt, Zmod<M>> for Reduction<M> {
+    fn apply(&self, value: BigInt) -> Zmod<M> {
+        Zmod::new(value.mod_floor(&BigInt::from(M)).to_u64().unwrap())
+    }
+}
+
+// `n ↦ n / 1`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct Inclusion;
+
+impl<T: Integer + Clone> Homomorphism<T, Ratio<T>> for Inclusion {
+    fn apply(&self, value: T) -> Ratio<T> { Ratio::from_integer(value) }
+}
+
+// `p ↦ p(point)`, a homomorphism when the point commutes with the coefficients
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Evaluation<T> {
+    pub point: T,
+}
+
+impl<T: Semiring + Clone> Homomorphism<Polynomial<T>, T> for Evaluation<T> {
+    fn apply(&self, value: Polynomial<T>) -> T { value.evaluate(self.point.clone()) }
+}
+
+// applies a homomorphism of the entries to every entry
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Entrywise<H>(pub H);
+
+impl<A, B, H: Homomorphism<A, B>> Homomorphism<Matrix<A>, Matrix<B>> for Entrywise<H> {
+    fn apply(&self, value: Matrix<A>) -> Matrix<B> {
+        match value {
+            Matrix::InfiniteDiagonal(diagonal) => Matrix::InfiniteDiagonal(self.0.apply(diagonal)),
+            Matrix::Finite(matrix) => Matrix::Finite(matrix.map(|x| self.0.apply(x))),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::rational::Ratio;
+    use crate::implementations::homomorphism::{Entrywise, Evaluation, Inclusion, Reduction};
+    use crate::matrix;
+    use crate::matrix::Matrix;
+    use crate::modular::Zmod;
+    use crate::polynomial::Polynomial;
+    use crate::structures::homomorphism::{check_homomorphism, Homomorphism, HomomorphismViolation};
+
+    fn integers() -> Vec<BigInt> {
+        [-1000000007i64, -12, -1, 0, 1, 2, 97, 1 << 40].into_iter().map(BigInt::from).collect()
+    }
+
+    #[test]
+    fn reduction_and_inclusion() {
+        assert_eq!(check_homomorphism::<_, Zmod<97>, _>(&Reduction::<97>, &integers()), Ok(()));
+        assert_eq!(Reduction::<7>.apply(BigInt::from(-1)), Zmod::new(6));
+        assert_eq!(check_homomorphism::<i64, Ratio<i64>, _>(&Inclusion, &[-3, 0, 1, 5, 8]), Ok(()));
+        assert_eq!(check_homomorphism::<i64, i64, _>(&|x: i64| 2 * x, &[1, 2]), Err(HomomorphismViolation::One));
+        assert_eq!(check_homomorphism::<i64, i64, _>(&|x: i64| x * x, &[1, 2]), Err(HomomorphismViolation::Plus(1, 1)));
+    }
+
+    #[test]
+    fn evaluation() {
+        let samples = [vec![1, 2, 3], vec![], vec![-1, 0, 0, 4], vec![5]].map(Polynomial::new);
+        let evaluation = Evaluation { point: 3 };
+        assert_eq!(evaluation.apply(samples[0].clone()), 34);
+        assert_eq!(check_homomorphism(&evaluation, &samples), Ok(()));
+    }
+
+    #[test]
+    fn entrywise() {
+        let lift = Entrywise(Reduction::<5>);
+        let samples = [
+            matrix![BigInt::from(7), BigInt::from(-3); BigInt::from(4), BigInt::from(10)],
+            matrix![BigInt::from(1), BigInt::from(2); BigInt::from(3), BigInt::from(4)],
+            Matrix::InfiniteDiagonal(BigInt::from(3)),
+        ];
+        assert_eq!(check_homomorphism(&lift, &samples), Ok(()));
+        assert_eq!(lift.apply(samples[0].clone()), matrix![Zmod::new(2), Zmod::new(2); Zmod::new(4), Zmod::new(0)]);
+        assert_eq!(check_homomorphism(&Entrywise(Inclusion), &[matrix![1, 2; 3, 4], Matrix::InfiniteDiagonal(2)]), Ok(()));
+    }
+}
@@ -0,0 +1,250 @@
+use std::marker::PhantomData;
+use crate::implementations::finite_matrix::FiniteMatrixSum;
+use crate::matrix::FiniteMatrix;
+use crate::matrix::finite::Shape;
+use crate::power::modular::mul_mod;
+use crate::structures::basics::{Associative, Commutative, Magma};
+use crate::structures::cayley_table::{CayleyGroup, CayleyTable};
+use crate::structures::instances::{InvertibleInstance, MagmaInstance, SemiringInstance, SemiringWithOneInstance, WithIdentityElementInstance};
+use crate::structures::module_like::ModuleInstance;
+use crate::structures::ring_like::{multi, Ring, unary_minus, zero};
+
+// residues `0..modulus` with a modulus chosen at runtime
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct ModularAddition {
+    pub modulus: u64,
+}
+
+impl Associative for ModularAddition {}
+
+impl Commutative for ModularAddition {}
+
+impl MagmaInstance<u64> for ModularAddition {
+    fn op(&self, operand1: u64, operand2: u64) -> u64 {
+        ((operand1 as u128 + operand2 as u128) % self.modulus as u128) as u64
+    }
+}
+
+impl WithIdentityElementInstance<u64> for ModularAddition {
+    fn identity(&self) -> u64 { 0 }
+}
+
+impl InvertibleInstance<u64> for ModularAddition {
+    fn inverse(&self, operand: u64) -> u64 { (self.modulus - operand % self.modulus) % self.modulus }
+}
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct ModularMultiplication {
+    pub modulus: u64,
+}
+
+impl Associative for ModularMultiplication {}
+
+impl Commutative for ModularMultiplication {}
+
+impl MagmaInstance<u64> for ModularMultiplication {
+    fn op(&self, operand1: u64, operand2: u64) -> u64 { mul_mod(operand1, operand2, self.modulus) }
+}
+
+impl WithIdentityElementInstance<u64> for ModularMultiplication {
+    fn identity(&self) -> u64 { 1 % self.modulus }
+}
+
+// the group of units, inverting the rest panics
+impl InvertibleInstance<u64> for ModularMultiplication {
+    fn inverse(&self, operand: u64) -> u64 {
+        let (mut r0, mut r1) = (self.modulus as i128, (operand % self.modulus) as i128);
+        let (mut s0, mut s1) = (0i128, 1i128);
+        while r1 != 0 {
+            let quotient = r0 / r1;
+            (r0, r1) = (r1, r0 - quotient * r1);
+            (s0, s1) = (s1, s0 - quotient * s1);
+        }
+        assert_eq!(r0, 1, "Inverting a non-invertible residue");
+        s0.rem_euclid(self.modulus as i128) as u64
+    }
+}
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct ModularRing {
+    sum: ModularAddition,
+    multi: ModularMultiplication,
+}
+
+impl ModularRing {
+    pub fn new(modulus: u64) -> ModularRing {
+        assert_ne!(modulus, 0, "Modulus must be positive");
+        ModularRing { sum: ModularAddition { modulus }, multi: ModularMultiplication { modulus } }
+    }
+
+    pub fn modulus(&self) -> u64 { self.sum.modulus }
+}
+
+impl SemiringInstance<u64> for ModularRing {
+    type Sum = ModularAddition;
+    type Multi = ModularMultiplication;
+
+    fn sum(&self) -> &Self::Sum { &self.sum }
+    fn multi(&self) -> &Self::Multi { &self.multi }
+}
+
+
+impl<T: Clone> FiniteMatrix<T> {
+    pub fn multiply_in<S: SemiringInstance<T>>(&self, ring: &S, other: &FiniteMatrix<T>) -> FiniteMatrix<T> {
+        assert_eq!(self.width(), other.height());
+        let shape = Shape { height: self.height(), width: other.width() };
+        FiniteMatrix::from_indexed_generator(shape, |x, y|
+            (0..self.width()).fold(ring.sum().identity(), |result, k|
+                ring.sum().op(result, ring.multi().op(self[(x, k)].clone(), other[(k, y)].clone()))
+            ),
+        )
+    }
+}
+
+// square matrices of a runtime size over a ring instance for elements `T`
+#[derive(Clone, Debug)]
+pub struct MatrixMultiplication<S, T> {
+    pub ring: S,
+    pub size: usize,
+    element: PhantomData<T>,
+}
+
+impl<S, T> MatrixMultiplication<S, T> {
+    pub fn new(ring: S, size: usize) -> MatrixMultiplication<S, T> {
+        MatrixMultiplication { ring, size, element: PhantomData }
+    }
+}
+
+// matrix products are associative because the semiring multiplication is associative and distributes over the sum
+impl<T, S: SemiringInstance<T>> Associative for MatrixMultiplication<S, T> {}
+
+impl<T: Clone, S: SemiringInstance<T>> MagmaInstance<FiniteMatrix<T>> for MatrixMultiplication<S, T> {
+    fn op(&self, operand1: FiniteMatrix<T>, operand2: FiniteMatrix<T>) -> FiniteMatrix<T> {
+        operand1.multiply_in(&self.ring, &operand2)
+    }
+}
+
+impl<T: Clone, S: SemiringWithOneInstance<T>> WithIdentityElementInstance<FiniteMatrix<T>> for MatrixMultiplication<S, T> {
+    fn identity(&self) -> FiniteMatrix<T> {
+        let shape = Shape { height: self.size, width: self.size };
+        FiniteMatrix::from_indexed_generator(shape, |x, y| if x == y { self.ring.multi().identity() } else { self.ring.sum().identity() })
+    }
+}
+
+// matrices of a runtime shape as a module over their entries, the shape gives the zero that `FiniteMatrix` lacks statically
+#[derive(Clone, Debug)]
+pub struct MatrixModule<T> {
+    pub shape: Shape,
+    element: PhantomData<T>,
+}
+
+impl<T> MatrixModule<T> {
+    pub fn new(shape: Shape) -> MatrixModule<T> {
+        MatrixModule { shape, element: PhantomData }
+    }
+}
+
+impl<T: Ring> Associative for MatrixModule<T> {}
+
+impl<T: Ring> Commutative for MatrixModule<T> {}
+
+// panics for matrices of another shape
+impl<T: Ring> MagmaInstance<FiniteMatrix<T>> for MatrixModule<T> {
+    fn op(&self, operand1: FiniteMatrix<T>, operand2: FiniteMatrix<T>) -> FiniteMatrix<T> {
+        assert_eq!(operand1.shape(), self.shape, "Operands must have the shape of the module");
+        FiniteMatrixSum::operation(operand1, operand2)
+    }
+}
+
+impl<T: Ring> WithIdentityElementInstance<FiniteMatrix<T>> for MatrixModule<T> {
+    fn identity(&self) -> FiniteMatrix<T> { FiniteMatrix::from_indexed_generator(self.shape, |_, _| zero()) }
+}
+
+impl<T: Ring> InvertibleInstance<FiniteMatrix<T>> for MatrixModule<T> {
+    fn inverse(&self, operand: FiniteMatrix<T>) -> FiniteMatrix<T> { operand.map(unary_minus) }
+}
+
+impl<T: Ring + Clone> ModuleInstance<T, FiniteMatrix<T>> for MatrixModule<T> {
+    type Sum = MatrixModule<T>;
+
+    fn sum(&self) -> &Self::Sum { self }
+
+    fn scale(&self, scalar: T, vector: FiniteMatrix<T>) -> FiniteMatrix<T> { vector.map(|x| multi(scalar.clone(), x)) }
+}
+
+
+impl MagmaInstance<usize> for CayleyTable {
+    fn op(&self, operand1: usize, operand2: usize) -> usize { self.operation(operand1, operand2) }
+}
+
+impl Associative for CayleyGroup {}
+
+impl MagmaInstance<usize> for CayleyGroup {
+    fn op(&self, operand1: usize, operand2: usize) -> usize { self.table().operation(operand1, operand2) }
+}
+
+impl WithIdentityElementInstance<usize> for CayleyGroup {
+    fn identity(&self) -> usize { self.identity() }
+}
+
+impl InvertibleInstance<usize> for CayleyGroup {
+    fn inverse(&self, operand: usize) -> usize { self.inverse(operand) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::fibonacci::{fibonacci, fibonacci_in};
+    use crate::implementations::instances::{MatrixMultiplication, ModularMultiplication, ModularRing};
+    use crate::matrix::FiniteMatrix;
+    use crate::matrix::finite::Shape;
+    use crate::modular::Zmod;
+    use crate::power::instance::{pow, signed_pow};
+    use crate::structures::cayley_table::{CayleyGroup, CayleyTable};
+    use crate::structures::instances::{GroupInstance, RingWithOneInstance, StaticSemiring};
+
+    fn supplier_ring_instance<R: RingWithOneInstance<T>, T>(_: &R) {}
+
+    fn supplier_group_instance<G: GroupInstance<T>, T>(_: &G) {}
+
+    #[test]
+    fn runtime_modulus() {
+        let modulus = "1000000007".parse().unwrap();
+        let ring = ModularRing::new(modulus);
+        supplier_ring_instance(&ring);
+        let units = ModularMultiplication { modulus };
+        supplier_group_instance(&units);
+        assert_eq!(pow(&units, 2, modulus - 1), 1);
+        assert_eq!(units.op(signed_pow(&units, 3, -5), pow(&units, 3, 5)), 1);
+        let index = 10u64.pow(18);
+        assert_eq!(fibonacci_in(&ring, index), fibonacci::<Zmod<1000000007>>(index).value());
+        assert_eq!(fibonacci_in(&ModularRing::new(10), 60), 0);
+    }
+
+    #[test]
+    fn runtime_matrix_size() {
+        let ring = StaticSemiring::<i64>::new();
+        for size in [1, 3, 5] {
+            let shift = FiniteMatrix::from_indexed_generator(Shape { height: size, width: size }, |x, y| i64::from((x + 1) % size == y));
+            let matrices = MatrixMultiplication::new(StaticSemiring::<i64>::new(), size);
+            assert_eq!(pow(&matrices, shift.clone(), size as u64), pow(&matrices, shift, 0));
+        }
+        let a = FiniteMatrix::from_iter(Shape { height: 1, width: 2 }, [1i64, 2].into_iter());
+        let b = FiniteMatrix::from_iter(Shape { height: 2, width: 1 }, [3i64, 4].into_iter());
+        assert_eq!(a.multiply_in(&ring, &b)[(0, 0)], 11);
+    }
+
+    #[test]
+    fn table_instance() {
+        let table = CayleyTable::from_operation(5, |a, b| (a + b) % 5).unwrap();
+        assert_eq!(table.op(3, 4), 2);
+        let group = CayleyGroup::new(table).unwrap();
+        supplier_group_instance(&group);
+        assert_eq!(signed_pow(&group, 2, -3), 4);
+        assert_eq!(pow(&group, 3, 5), 0);
+        let subtraction = CayleyTable::from_operation(3, |a, b| (a + 3 - b) % 3).unwrap();
+        assert_eq!(subtraction.into_group(), None);
+    }
+}
@@ -1,8 +1,11 @@
+use std::marker::PhantomData;
+use std::num::{NonZero, Saturating, Wrapping};
 use num::{BigInt, BigUint, Complex, Integer, Num, One, Zero};
+use crate::checked::Checked;
 use num::rational::Ratio;
 use crate::implementations::helpers::sum::AssociativeCommutativeSum;
 use crate::implementations::helpers::multi::AssociativeCommutativeMulti;
-use crate::structures::basics::WithIdentityElement;
+use crate::structures::basics::{Associative, Commutative, Magma, WithIdentityElement};
 use crate::structures::ring_like::Semiring;
 
 macro_rules! impls {
@@ -44,9 +47,7 @@ impls!(i32, 0, 1);
 impls!(u32, 0, 1);
 impls!(i64, 0, 1);
 impls!(u64, 0, 1);
-#[cfg(has_i128)]
 impls!(i128, 0, 1);
-#[cfg(has_i128)]
 impls!(u128, 0, 1);
 impls!(isize, 0, 1);
 impls!(usize, 0, 1);
@@ -56,3 +57,154 @@ impls!(BigInt, BigInt::zero(), BigInt::one());
 impls!(BigUint, BigUint::zero(), BigUint::one());
 impls!(Complex<T>, Complex::<T>::zero(), Complex::<T>::one(), T, Num);
 impls!(Ratio<T>, Ratio::<T>::zero(), Ratio::<T>::one(), T, Integer);
+
+// arithmetic modulo `2ⁿ`, so unsigned types are rings too
+macro_rules! wrapping_impls {
+    ($($t:ty),+) => {$(
+impls!(Wrapping<$t>, Wrapping(0), Wrapping(1));
+    )+};
+}
+
+wrapping_impls!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+// checked addition of signed integers depends on the order of evaluation: `(MAX + 1) + (-1)` overflows, `MAX + (1 + (-1))` does not,
+// unsigned ones are the quotient of `ℕ` identifying everything above `MAX`
+macro_rules! checked_impls {
+    ($($t:ty),+) => {$(
+impls!(Checked<$t>, Checked::new(0), Checked::new(1));
+    )+};
+}
+
+checked_impls!(u8, u16, u32, u64, u128, usize);
+
+// saturating addition of signed integers is not associative: `(MAX + 1) + (-1) ≠ MAX + (1 + (-1))`
+macro_rules! saturating_impls {
+    ($($t:ty),+) => {$(
+impls!(Saturating<$t>, Saturating(0), Saturating(1));
+    )+};
+}
+
+saturating_impls!(u8, u16, u32, u64, u128, usize);
+
+
+// multiplicative monoid of the non-zero integers, there is no zero so they are no semiring
+// overflow panics also in release builds, where the wrapped product could be zero
+pub struct NonZeroMulti<T>(PhantomData<T>);
+
+macro_rules! non_zero_impls {
+    ($($t:ty),+) => {$(
+impl Magma<NonZero<$t>> for NonZeroMulti<NonZero<$t>> {
+    fn operation(operand1: NonZero<$t>, operand2: NonZero<$t>) -> NonZero<$t> {
+        operand1.get().checked_mul(operand2.get()).and_then(NonZero::new).expect("product of non-zero integers overflowed")
+    }
+}
+
+impl WithIdentityElement<NonZero<$t>> for NonZeroMulti<NonZero<$t>> {
+    fn identity() -> NonZero<$t> { NonZero::new(1).unwrap() }
+}
+
+impl Associative for NonZeroMulti<NonZero<$t>> {}
+
+impl Commutative for NonZeroMulti<NonZero<$t>> {}
+    )+};
+}
+
+non_zero_impls!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize);
+
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZero, Saturating, Wrapping};
+    use num::{BigInt, BigRational};
+    use num::traits::Pow;
+    use super::NonZeroMulti;
+    use crate::checked::Checked;
+    use crate::fibonacci::fibonacci;
+    use crate::matrix;
+    use crate::matrix::Matrix;
+    use crate::power::monoid;
+    use crate::power::signed;
+    use crate::power::unsigned::pow;
+    use crate::structures::group_like::CommutativeMonoid;
+    use crate::structures::ring_like::{multi, plus, RingWithOne, Semiring, SemiringWithOne};
+
+    fn supplier_ring<T: RingWithOne>() {}
+
+    fn supplier_semiring<T: SemiringWithOne>() {}
+
+    fn supplier_commutative_monoid<G: CommutativeMonoid<T>, T>() {}
+
+    #[test]
+    fn wrapping() {
+        supplier_ring::<Wrapping<u8>>();
+        supplier_ring::<Wrapping<i64>>();
+        assert_eq!(fibonacci::<Wrapping<i32>>(50), Wrapping(12586269025u64 as i32));
+        assert_eq!(fibonacci::<Wrapping<u64>>(100), Wrapping((354224848179261915075u128 % (1 << 64)) as u64));
+        assert_eq!(pow(Wrapping(3u8), 5), Wrapping(243));
+        assert_eq!(pow(Wrapping(3u8), 6), Wrapping((729 % 256) as u8));
+    }
+
+    #[test]
+    fn saturating() {
+        supplier_semiring::<Saturating<u32>>();
+        let step = matrix![Saturating(1u64), Saturating(1); Saturating(1), Saturating(0)];
+        let power = monoid::pow::<<Matrix<Saturating<u64>> as Semiring>::Multi, _>(step.clone(), 93);
+        assert_eq!(power, matrix![Saturating(u64::MAX), Saturating(12200160415121876738); Saturating(12200160415121876738), Saturating(7540113804746346429)]);
+        assert_eq!(plus(Saturating(u8::MAX), Saturating(1)), Saturating(u8::MAX));
+        assert_eq!(monoid::pow::<<Saturating<u16> as Semiring>::Multi, _>(Saturating(10), 5), Saturating(u16::MAX));
+    }
+
+    #[test]
+    fn checked() {
+        supplier_semiring::<Checked<u8>>();
+        supplier_semiring::<Checked<u128>>();
+        let step = matrix![Checked::new(1u32), Checked::new(1); Checked::new(1), Checked::new(0)];
+        let power = monoid::pow::<<Matrix<Checked<u32>> as Semiring>::Multi, _>(step.clone(), 46);
+        assert_eq!(power, matrix![Checked::new(2971215073), Checked::new(1836311903); Checked::new(1836311903), Checked::new(1134903170)]);
+        let power = monoid::pow::<<Matrix<Checked<u32>> as Semiring>::Multi, _>(step, 47);
+        assert_eq!(power, matrix![Checked::overflow(), Checked::new(2971215073); Checked::new(2971215073), Checked::new(1836311903)]);
+        assert_eq!(monoid::pow::<<Checked<u8> as Semiring>::Multi, _>(Checked::new(2), 7).value(), Some(128));
+        assert_eq!(monoid::pow::<<Checked<u8> as Semiring>::Multi, _>(Checked::new(2), 8).to_string(), "overflow");
+        let big = Checked::new(1u8 << 7);
+        let product = multi(matrix![big, Checked::new(0); Checked::new(0), Checked::new(1)], matrix![Checked::new(2), Checked::new(0); Checked::new(0), Checked::new(1)]);
+        assert_eq!(product, matrix![Checked::overflow(), Checked::new(0); Checked::new(0), Checked::new(1)]);
+        // multiplication stays associative since an overflow times zero is zero
+        let (max, two, zero) = (Checked::new(u16::MAX), Checked::new(2), Checked::new(0));
+        assert_eq!(multi(multi(max, two), zero), multi(max, multi(two, zero)));
+    }
+
+    #[test]
+    fn non_zero() {
+        type Multi<T> = NonZeroMulti<NonZero<T>>;
+        supplier_commutative_monoid::<Multi<u128>, NonZero<u128>>();
+        supplier_commutative_monoid::<Multi<i8>, NonZero<i8>>();
+        let three = NonZero::new(3u128).unwrap();
+        assert_eq!(monoid::pow::<Multi<u128>, _>(three, 80).get(), 3u128.pow(80));
+        assert_eq!(monoid::pow::<Multi<i32>, _>(NonZero::new(-2).unwrap(), 0).get(), 1);
+        assert_eq!(monoid::pow::<Multi<i32>, _>(NonZero::new(-2).unwrap(), 31).get(), i32::MIN);
+        // `16 · 16` wraps to zero in `u8`
+        assert!(std::panic::catch_unwind(|| monoid::pow::<Multi<u8>, _>(NonZero::new(16).unwrap(), 2)).is_err());
+    }
+
+    #[test]
+    fn one_hundred_twenty_eight_bits() {
+        supplier_ring::<i128>();
+        supplier_semiring::<u128>();
+        assert_eq!(fibonacci::<i128>(183), 78569350599398894027251472817058687522);
+        assert_eq!(pow(-3i128, 79), -(3i128.pow(79)));
+        assert_eq!(monoid::pow::<<u128 as Semiring>::Multi, _>(2, 127), 1 << 127);
+        assert_eq!(fibonacci::<Wrapping<u128>>(187), Wrapping(198239973509362327032045173661212819077));
+        assert_eq!(pow(Wrapping(3u128), 81), Wrapping(3u128.pow(80).wrapping_mul(3)));
+    }
+
+    #[test]
+    fn big_rational() {
+        supplier_ring::<BigRational>();
+        let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+        assert_eq!(pow(half.clone(), 100), BigRational::new(BigInt::from(1), BigInt::from(2).pow(100u32)));
+        assert_eq!(signed::pow(half, -3), BigRational::from_integer(BigInt::from(8)));
+        let f = fibonacci::<BigRational>(300);
+        assert!(f.is_integer());
+        assert_eq!(f.to_integer().to_string(), "222232244629420445529739893461909967206666939096499764990979600");
+    }
+}
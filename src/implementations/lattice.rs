@@ -0,0 +1,218 @@
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use num::{BigInt, Integer};
+use num::rational::Ratio;
+use crate::structures::basics::{Associative, Commutative, Idempotent, Magma, WithIdentityElement};
+use crate::implementations::helpers::multi::AssociativeCommutativeMulti;
+use crate::structures::lattice_like::{Complemented, Distributive, Lattice, Ordered};
+
+// the lattice of a total order, every chain is distributive
+// floats are no total order: `NaN` is incomparable, so the minimum would depend on the order of the operands
+pub struct Minimum<T>(PhantomData<T>);
+
+pub struct Maximum<T>(PhantomData<T>);
+
+impl<T: Ord> Magma<T> for Minimum<T> {
+    fn operation(operand1: T, operand2: T) -> T { operand1.min(operand2) }
+}
+
+impl<T: Ord> Magma<T> for Maximum<T> {
+    fn operation(operand1: T, operand2: T) -> T { operand1.max(operand2) }
+}
+
+impl<T: Ord> Associative for Minimum<T> {}
+
+impl<T: Ord> Commutative for Minimum<T> {}
+
+impl<T: Ord> Idempotent for Minimum<T> {}
+
+impl<T: Ord> Distributive for Minimum<T> {}
+
+impl<T: Ord> Associative for Maximum<T> {}
+
+impl<T: Ord> Commutative for Maximum<T> {}
+
+impl<T: Ord> Idempotent for Maximum<T> {}
+
+macro_rules! total_order {
+    ($t:ty, $bottom:expr, $top:expr) => {
+impl Lattice for $t {
+    type Meet = Minimum<$t>;
+    type Join = Maximum<$t>;
+}
+
+impl WithIdentityElement<$t> for Minimum<$t> {
+    fn identity() -> $t { $top }
+}
+
+impl WithIdentityElement<$t> for Maximum<$t> {
+    fn identity() -> $t { $bottom }
+}
+    };
+}
+
+total_order!(i8, i8::MIN, i8::MAX);
+total_order!(u8, u8::MIN, u8::MAX);
+total_order!(i16, i16::MIN, i16::MAX);
+total_order!(u16, u16::MIN, u16::MAX);
+total_order!(i32, i32::MIN, i32::MAX);
+total_order!(u32, u32::MIN, u32::MAX);
+total_order!(i64, i64::MIN, i64::MAX);
+total_order!(u64, u64::MIN, u64::MAX);
+total_order!(i128, i128::MIN, i128::MAX);
+total_order!(u128, u128::MIN, u128::MAX);
+total_order!(isize, isize::MIN, isize::MAX);
+total_order!(usize, usize::MIN, usize::MAX);
+total_order!(bool, false, true);
+
+impl Complemented<bool> for Minimum<bool> {
+    fn complement(operand: bool) -> bool { !operand }
+}
+
+impl<T: Integer + Clone> Lattice for Ratio<T> {
+    type Meet = Minimum<Ratio<T>>;
+    type Join = Maximum<Ratio<T>>;
+}
+
+// wrapping integers and residues wrap around, so their order is not compatible with the sum
+macro_rules! ordered {
+    ($($t:ty),+) => { $(impl Ordered for AssociativeCommutativeMulti<$t> {})+ };
+}
+
+ordered!(i8, i16, i32, i64, i128, isize, BigInt);
+
+impl<T: Integer + Clone> Ordered for AssociativeCommutativeMulti<Ratio<T>> {}
+
+
+// subsets ordered by inclusion, the empty set is the bottom
+pub struct Intersection<S>(PhantomData<S>);
+
+pub struct Union<S>(PhantomData<S>);
+
+impl<S> Associative for Intersection<S> {}
+
+impl<S> Commutative for Intersection<S> {}
+
+impl<S> Idempotent for Intersection<S> {}
+
+impl<S> Distributive for Intersection<S> {}
+
+impl<S> Associative for Union<S> {}
+
+impl<S> Commutative for Union<S> {}
+
+impl<S> Idempotent for Union<S> {}
+
+impl<S: Default> WithIdentityElement<S> for Union<S> {
+    fn identity() -> S { S::default() }
+}
+
+impl<T: Ord> Magma<BTreeSet<T>> for Intersection<BTreeSet<T>> {
+    fn operation(mut operand1: BTreeSet<T>, operand2: BTreeSet<T>) -> BTreeSet<T> {
+        operand1.retain(|x| operand2.contains(x));
+        operand1
+    }
+}
+
+impl<T: Ord> Magma<BTreeSet<T>> for Union<BTreeSet<T>> {
+    fn operation(mut operand1: BTreeSet<T>, operand2: BTreeSet<T>) -> BTreeSet<T> {
+        operand1.extend(operand2);
+        operand1
+    }
+}
+
+impl<T: Ord> Lattice for BTreeSet<T> {
+    type Meet = Intersection<BTreeSet<T>>;
+    type Join = Union<BTreeSet<T>>;
+}
+
+impl<T: Hash + Eq> Magma<HashSet<T>> for Intersection<HashSet<T>> {
+    fn operation(mut operand1: HashSet<T>, operand2: HashSet<T>) -> HashSet<T> {
+        operand1.retain(|x| operand2.contains(x));
+        operand1
+    }
+}
+
+impl<T: Hash + Eq> Magma<HashSet<T>> for Union<HashSet<T>> {
+    fn operation(mut operand1: HashSet<T>, operand2: HashSet<T>) -> HashSet<T> {
+        operand1.extend(operand2);
+        operand1
+    }
+}
+
+impl<T: Hash + Eq> Lattice for HashSet<T> {
+    type Meet = Intersection<HashSet<T>>;
+    type Join = Union<HashSet<T>>;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+    use num::BigInt;
+    use num::rational::Ratio;
+    use crate::structures::group_like::BoundedSemilattice;
+    use crate::structures::lattice_like::{abs, BooleanAlgebra, bottom, BoundedLattice, complement, DistributiveLattice, join, Lattice, meet, OrderedField, OrderedRing, signum, top};
+
+    fn supplier_bounded_lattice<T: BoundedLattice>() {}
+
+    fn supplier_distributive_lattice<T: DistributiveLattice>() {}
+
+    fn supplier_boolean_algebra<T: BooleanAlgebra>() {}
+
+    fn supplier_ordered_ring<T: OrderedRing>() {}
+
+    fn supplier_ordered_field<T: OrderedField>() {}
+
+    fn supplier_bounded_semilattice<G: BoundedSemilattice<T>, T>() {}
+
+    fn absorption<T: Lattice + Clone + PartialEq>(x: T, y: T) -> bool {
+        meet(x.clone(), join(x.clone(), y.clone())) == x && join(x.clone(), meet(x.clone(), y)) == x
+    }
+
+    #[test]
+    fn hierarchy() {
+        supplier_bounded_lattice::<u8>();
+        supplier_bounded_lattice::<i128>();
+        supplier_distributive_lattice::<Ratio<i32>>();
+        supplier_distributive_lattice::<BTreeSet<char>>();
+        supplier_boolean_algebra::<bool>();
+        supplier_ordered_ring::<i64>();
+        supplier_ordered_ring::<BigInt>();
+        supplier_ordered_field::<Ratio<i64>>();
+        supplier_bounded_semilattice::<<HashSet<u8> as Lattice>::Join, HashSet<u8>>();
+    }
+
+    #[test]
+    fn total_orders() {
+        assert_eq!(meet(3, -7), -7);
+        assert_eq!(join(Ratio::new(1, 2), Ratio::new(2, 3)), Ratio::new(2, 3));
+        assert_eq!(top::<u16>(), u16::MAX);
+        assert_eq!(bottom::<i64>(), i64::MIN);
+        assert_eq!(meet(i32::MAX, 25), 25);
+        assert!(absorption(4, 9) && absorption(Ratio::new(-3, 2), Ratio::new(1, 4)));
+        assert!(!complement(true));
+        assert_eq!((meet(true, complement(true)), join(false, complement(false))), (bottom(), top()));
+    }
+
+    #[test]
+    fn sets() {
+        let a = BTreeSet::from([1, 2, 3]);
+        let b = BTreeSet::from([2, 3, 4]);
+        assert_eq!(meet(a.clone(), b.clone()), BTreeSet::from([2, 3]));
+        assert_eq!(join(a.clone(), b.clone()), BTreeSet::from([1, 2, 3, 4]));
+        assert!(absorption(a.clone(), b));
+        assert_eq!(join(HashSet::from(['x']), HashSet::new()), HashSet::from(['x']));
+    }
+
+    #[test]
+    fn ordered() {
+        assert_eq!(abs(-5), 5);
+        assert_eq!(abs(Ratio::new(-1, 3)), Ratio::new(1, 3));
+        assert_eq!(signum(-25), -1);
+        assert_eq!(signum(Ratio::new(4, 7)), Ratio::from_integer(1));
+        assert_eq!(signum(0i8), 0);
+        assert_eq!(abs(BigInt::from(-7)), BigInt::from(7));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::implementations::helpers::multi::AssociativeCommutativeMulti;
+use crate::implementations::helpers::sum::AssociativeCommutativeSum;
+use crate::modular::{Zmod, Zp};
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::ring_like::Semiring;
+
+impl<const M: u64> WithIdentityElement<Zmod<M>> for AssociativeCommutativeSum<Zmod<M>> {
+    fn identity() -> Zmod<M> { Zmod::new(0) }
+}
+
+impl<const M: u64> WithIdentityElement<Zmod<M>> for AssociativeCommutativeMulti<Zmod<M>> {
+    fn identity() -> Zmod<M> { Zmod::new(1) }
+}
+
+impl<const M: u64> Semiring for Zmod<M> {
+    type Sum = AssociativeCommutativeSum<Zmod<M>>;
+    type Multi = AssociativeCommutativeMulti<Zmod<M>>;
+}
+
+impl<const P: u64> WithIdentityElement<Zp<P>> for AssociativeCommutativeSum<Zp<P>> {
+    fn identity() -> Zp<P> { Zp::new(0) }
+}
+
+impl<const P: u64> WithIdentityElement<Zp<P>> for AssociativeCommutativeMulti<Zp<P>> {
+    fn identity() -> Zp<P> { Zp::new(1) }
+}
+
+impl<const P: u64> Semiring for Zp<P> {
+    type Sum = AssociativeCommutativeSum<Zp<P>>;
+    type Multi = AssociativeCommutativeMulti<Zp<P>>;
+}
+
+// dividing by 0 panics as for `Zmod`
+impl<const P: u64> Invertible<Zp<P>> for AssociativeCommutativeMulti<Zp<P>> {
+    fn inverse(operand: Zp<P>) -> Zp<P> { Zp::new(1) / operand }
+}
+
+
+// the multiplicative group of the units modulo `M`, which `Zmod<M>` as a ring is not, non-units have no inverse
+pub struct ZmodUnits<const M: u64>;
+
+impl<const M: u64> Magma<Zmod<M>> for ZmodUnits<M> {
+    fn operation(operand1: Zmod<M>, operand2: Zmod<M>) -> Zmod<M> { operand1 * operand2 }
+}
+
+impl<const M: u64> Associative for ZmodUnits<M> {}
+
+impl<const M: u64> Commutative for ZmodUnits<M> {}
+
+impl<const M: u64> WithIdentityElement<Zmod<M>> for ZmodUnits<M> {
+    fn identity() -> Zmod<M> { Zmod::new(1) }
+}
+
+impl<const M: u64> Invertible<Zmod<M>> for ZmodUnits<M> {
+    fn inverse(operand: Zmod<M>) -> Zmod<M> { operand.try_inverse().expect("Only units modulo M are invertible") }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::fibonacci::fibonacci;
+    use crate::implementations::modular::ZmodUnits;
+    use crate::modular::{Zmod, Zp};
+    use crate::power::{group, signed};
+    use crate::structures::group_like::AbelGroup;
+    use crate::structures::ring_like::{div, Field, minus, one, reciprocal, UnitDetection};
+
+    fn supplier_field<T: Field>() {}
+
+    fn supplier_unit_detection<T: UnitDetection>() {}
+
+    fn supplier_abel_group<G: AbelGroup<T>, T>() {}
+
+    #[test]
+    fn arithmetic() {
+        supplier_field::<Zp<7>>();
+        supplier_unit_detection::<Zmod<6>>();
+        type Z = Zp<7>;
+        assert_eq!(minus(Z::new(2), Z::new(5)), Z::new(4));
+        assert_eq!(Z::from_signed(-1), Z::new(6));
+        assert_eq!(reciprocal(Z::new(3)), Z::new(5));
+        assert_eq!(div(Z::new(1), Z::new(2)), Z::new(4));
+        assert_eq!(signed::pow(Z::new(3), -6), one());
+        assert_eq!(Zmod::<6>::new(4).try_inverse(), None);
+        const { assert!(Zmod::<7>::IS_PRIME && !Zmod::<6>::IS_PRIME && Zmod::<{ u64::MAX - 58 }>::IS_PRIME) };
+        let big = Zp::<{ u64::MAX - 58 }>::new(u64::MAX - 59);
+        assert_eq!(big * big, one());
+        assert_eq!(fibonacci::<Zmod<1_000_000_007>>(1000), Zmod::new(517691607));
+        assert_eq!(Z::new(3).residue(), Zmod::new(3));
+    }
+
+    #[test]
+    fn units() {
+        supplier_abel_group::<ZmodUnits<9>, Zmod<9>>();
+        assert_eq!(group::pow::<ZmodUnits<9>, _>(Zmod::new(2), -1), Zmod::new(5));
+        assert_eq!(group::pow::<ZmodUnits<25>, _>(Zmod::new(7), -2), Zmod::new(24));
+        assert!(std::panic::catch_unwind(|| group::pow::<ZmodUnits<6>, _>(Zmod::new(2), -1)).is_err());
+    }
+}
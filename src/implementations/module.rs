@@ -0,0 +1,112 @@
+use crate::implementations::matrix::MatrixSum;
+use crate::implementations::polynomial::PolynomialSum;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::structures::module_like::Module;
+use crate::structures::ring_like::{multi, Ring, Semiring};
+
+// finite matrices have no zero without a shape, here it is `InfiniteDiagonal(0)`, `MatrixModule` carries the shape instead
+impl<T: Ring + Clone> Module<T> for Matrix<T> {
+    type Sum = MatrixSum<T>;
+
+    fn scale(scalar: T, vector: Self) -> Self {
+        match vector {
+            Matrix::InfiniteDiagonal(x) => Matrix::InfiniteDiagonal(multi(scalar, x)),
+            Matrix::Finite(matrix) => Matrix::Finite(matrix.map(|x| multi(scalar.clone(), x))),
+        }
+    }
+}
+
+impl<T: Ring + Clone + PartialEq> Module<T> for Polynomial<T> {
+    type Sum = PolynomialSum<T>;
+
+    fn scale(scalar: T, vector: Self) -> Self {
+        Polynomial::new(vector.into_coefficients().into_iter().map(|x| multi(scalar.clone(), x)).collect())
+    }
+}
+
+impl<T: Ring + Clone, const N: usize> Module<T> for [T; N] {
+    type Sum = [<T as Semiring>::Sum; N];
+
+    fn scale(scalar: T, vector: Self) -> Self {
+        vector.map(|x| multi(scalar.clone(), x))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::implementations::instances::MatrixModule;
+    use crate::matrix;
+    use crate::matrix::{FiniteMatrix, Matrix};
+    use crate::matrix::finite::Shape;
+    use crate::modular::Zmod;
+    use crate::polynomial::Polynomial;
+    use crate::quaternion::Quaternion;
+    use crate::structures::instances::AbelGroupInstance;
+    use crate::structures::module_like::{linear_combination, linear_combination_in, Module, ModuleInstance, scale, vector_plus, vector_zero, VectorSpace};
+    use crate::structures::ring_like::Ring;
+
+    fn supplier_module<R: Ring, V: Module<R>>() {}
+
+    fn supplier_vector_space<V: VectorSpace<Ratio<i64>>>() {}
+
+    fn supplier_module_instance<R: Ring, V, M: ModuleInstance<R, V>>(_: &M) {}
+
+    fn supplier_abel_group_instance<G: AbelGroupInstance<T>, T>(_: &G) {}
+
+    #[test]
+    fn implementations() {
+        supplier_module::<i32, Matrix<i32>>();
+        supplier_module::<Quaternion<i32>, Matrix<Quaternion<i32>>>();
+        supplier_module::<Zmod<7>, Polynomial<Zmod<7>>>();
+        supplier_module::<i64, [i64; 3]>();
+        supplier_vector_space::<[Ratio<i64>; 2]>();
+        supplier_vector_space::<Polynomial<Ratio<i64>>>();
+    }
+
+    #[test]
+    fn generic_combinations() {
+        assert_eq!(linear_combination([(2, [1, 0, 3]), (-1, [0, 4, 1])]), [2, -4, 5]);
+        let polynomial = linear_combination([(3, Polynomial::new(vec![1, 1])), (-3, Polynomial::new(vec![0, 1, 2]))]);
+        assert_eq!(polynomial, Polynomial::new(vec![3, 0, -6]));
+        assert_eq!(linear_combination([(2, matrix![1, 2; 3, 4]), (1, Matrix::InfiniteDiagonal(1))]), matrix![3, 4; 6, 9]);
+        let finite = Matrix::Finite(FiniteMatrix::from_iter(Shape { height: 1, width: 2 }, [1, 2].into_iter()));
+        assert_eq!(linear_combination([(3, finite.clone()), (-3, finite)]), matrix![0, 0]);
+        assert_eq!(linear_combination::<i32, Matrix<i32>>([]), Matrix::InfiniteDiagonal(0));
+        // the empty matrix is not a zero of other shapes
+        let empty = Matrix::Finite(FiniteMatrix::from_iter(Shape::empty(), std::iter::empty()));
+        assert!(std::panic::catch_unwind(|| vector_plus::<i32, _>(empty, matrix![1, 2, 3; 4, 5, 6])).is_err());
+        assert_eq!(linear_combination::<i32, [i32; 2]>([]), [0, 0]);
+    }
+
+    #[test]
+    fn finite_matrices() {
+        let shape = Shape { height: 2, width: 3 };
+        let module = MatrixModule::<i32>::new(shape);
+        supplier_module_instance::<i32, FiniteMatrix<i32>, _>(&module);
+        supplier_abel_group_instance(module.sum());
+        let x = FiniteMatrix::from_iter(shape, [1, 0, 2, -1, 3, 0].into_iter());
+        let y = FiniteMatrix::from_iter(shape, [0, 1, 1, 1, 0, 5].into_iter());
+        let combination = linear_combination_in(&module, [(2, x.clone()), (-3, y)]);
+        assert_eq!(combination, FiniteMatrix::from_iter(shape, [2, -3, 1, -5, 6, -15].into_iter()));
+        assert_eq!(linear_combination_in(&module, []), FiniteMatrix::from_iter(shape, [0; 6].into_iter()));
+        assert_eq!(module.sum().op(x.clone(), module.sum().inverse(x)), module.sum().identity());
+        let square = FiniteMatrix::from_iter(Shape { height: 2, width: 2 }, [1, 2, 3, 4].into_iter());
+        assert!(std::panic::catch_unwind(|| module.sum().op(square.clone(), square)).is_err());
+    }
+
+    #[test]
+    fn module_laws() {
+        let (x, y) = ([1, -2], [4, 7]);
+        let (r, s) = (3, -5);
+        assert_eq!(scale(r, vector_plus::<i32, _>(x, y)), vector_plus::<i32, _>(scale(r, x), scale(r, y)));
+        assert_eq!(scale(r + s, x), vector_plus::<i32, _>(scale(r, x), scale(s, x)));
+        assert_eq!(scale(r * s, x), scale(r, scale(s, x)));
+        assert_eq!(scale(1, x), x);
+        assert_eq!(vector_zero::<i32, [i32; 2]>(), [0, 0]);
+        let i = Quaternion::new(0, 1, 0, 0);
+        assert_eq!(scale(i, matrix![Quaternion::new(0, 0, 1, 0)]), matrix![Quaternion::new(0, 0, 0, 1)]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+use crate::monoid_algebra::MonoidAlgebra;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::group_like::Monoid;
+use crate::structures::module_like::Module;
+use crate::structures::ring_like::{multi, one, Ring, Semiring, SemiringWithOne, unary_minus};
+
+pub struct MonoidAlgebraSum<G, T, R>(PhantomData<(G, T, R)>);
+
+impl<G, T, R: Semiring> Commutative for MonoidAlgebraSum<G, T, R> where R::Sum: Commutative {}
+
+impl<G, T, R: Semiring> Associative for MonoidAlgebraSum<G, T, R> where R::Sum: Associative {}
+
+impl<G, T: Ord, R: Semiring + PartialEq> WithIdentityElement<MonoidAlgebra<G, T, R>> for MonoidAlgebraSum<G, T, R> {
+    fn identity() -> MonoidAlgebra<G, T, R> { MonoidAlgebra::new([]) }
+}
+
+impl<G, T: Ord, R: Semiring + PartialEq> Magma<MonoidAlgebra<G, T, R>> for MonoidAlgebraSum<G, T, R> {
+    fn operation(operand1: MonoidAlgebra<G, T, R>, operand2: MonoidAlgebra<G, T, R>) -> MonoidAlgebra<G, T, R> {
+        MonoidAlgebra::new(operand1.into_terms().into_iter().chain(operand2.into_terms()))
+    }
+}
+
+impl<G, T: Ord, R: Semiring + PartialEq> Invertible<MonoidAlgebra<G, T, R>> for MonoidAlgebraSum<G, T, R> where R::Sum: Invertible<R> {
+    fn inverse(operand: MonoidAlgebra<G, T, R>) -> MonoidAlgebra<G, T, R> {
+        MonoidAlgebra::new(operand.into_terms().into_iter().map(|(element, coefficient)| (element, unary_minus(coefficient))))
+    }
+}
+
+
+pub struct MonoidAlgebraMul<G, T, R>(PhantomData<(G, T, R)>);
+
+impl<G: Monoid<T>, T: Ord + Clone, R: Semiring + Clone + PartialEq> Semiring for MonoidAlgebra<G, T, R> {
+    type Sum = MonoidAlgebraSum<G, T, R>;
+    type Multi = MonoidAlgebraMul<G, T, R>;
+}
+
+impl<G: Commutative, T, R: Semiring> Commutative for MonoidAlgebraMul<G, T, R> where R::Multi: Commutative {}
+
+impl<G: Associative, T, R: Semiring> Associative for MonoidAlgebraMul<G, T, R> where R::Multi: Associative {}
+
+impl<G: WithIdentityElement<T>, T: Ord, R: SemiringWithOne + PartialEq> WithIdentityElement<MonoidAlgebra<G, T, R>> for MonoidAlgebraMul<G, T, R> {
+    fn identity() -> MonoidAlgebra<G, T, R> { MonoidAlgebra::term(G::identity(), one()) }
+}
+
+impl<G: Magma<T>, T: Ord + Clone, R: Semiring + Clone + PartialEq> Magma<MonoidAlgebra<G, T, R>> for MonoidAlgebraMul<G, T, R> {
+    fn operation(operand1: MonoidAlgebra<G, T, R>, operand2: MonoidAlgebra<G, T, R>) -> MonoidAlgebra<G, T, R> {
+        operand1.multiply(&operand2)
+    }
+}
+
+impl<G: Monoid<T>, T: Ord + Clone, R: Ring + Clone + PartialEq> Module<R> for MonoidAlgebra<G, T, R> {
+    type Sum = MonoidAlgebraSum<G, T, R>;
+
+    fn scale(scalar: R, vector: Self) -> Self {
+        MonoidAlgebra::new(vector.into_terms().into_iter().map(|(element, coefficient)| (element, multi(scalar.clone(), coefficient))))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::implementations::permutation::Composition;
+    use crate::monoid_algebra::{GroupAlgebra, MonoidAlgebra};
+    use crate::permutation::Permutation;
+    use crate::polynomial::Polynomial;
+    use crate::power::unsigned;
+    use crate::structures::module_like::{Algebra, scale};
+    use crate::structures::ring_like::{CommutativeRingWithOne, minus, multi, one, plus, RingWithOne, Semiring};
+
+    type Natural = <u64 as Semiring>::Sum;
+    type Integer = <i64 as Semiring>::Sum;
+    type Laurent = MonoidAlgebra<Integer, i64, Ratio<i64>>;
+
+    fn supplier_commutative_ring<T: CommutativeRingWithOne>() {}
+
+    fn supplier_ring<T: RingWithOne>() {}
+
+    fn supplier_algebra<R: CommutativeRingWithOne, A: Algebra<R>>() {}
+
+    #[test]
+    fn polynomials() {
+        supplier_commutative_ring::<MonoidAlgebra<Natural, u64, i32>>();
+        supplier_algebra::<i32, MonoidAlgebra<Natural, u64, i32>>();
+        let p = Polynomial::new(vec![1, 2, 0, -3]);
+        let q = Polynomial::new(vec![-1, 0, 5]);
+        let product = multi(MonoidAlgebra::from(p.clone()), MonoidAlgebra::from(q.clone()));
+        assert_eq!(Polynomial::from(product), multi(p, q));
+        let x = MonoidAlgebra::<Natural, u64, i32>::basis(1);
+        assert_eq!(unsigned::pow(plus(x, one()), 3).to_string(), "1·[0] + 3·[1] + 3·[2] + 1·[3]");
+    }
+
+    #[test]
+    fn laurent_polynomials() {
+        let x = Laurent::basis(1);
+        let inverse = Laurent::basis(-1);
+        assert_eq!(multi(x.clone(), inverse.clone()), one());
+        let sum = plus(x, inverse);
+        let square = multi(sum.clone(), sum);
+        assert_eq!(square, Laurent::new([(-2, Ratio::from_integer(1)), (0, Ratio::from_integer(2)), (2, Ratio::from_integer(1))]));
+        assert_eq!(square.coefficient(&0), Ratio::from_integer(2));
+        assert_eq!(scale(Ratio::new(1, 2), square).coefficient(&2), Ratio::new(1, 2));
+        assert!(minus(Laurent::basis(3), Laurent::basis(3)).is_zero());
+    }
+
+    #[test]
+    fn group_algebra() {
+        type S3 = GroupAlgebra<Composition, Permutation, i32>;
+        supplier_ring::<S3>();
+        let elements = ["()", "(0 1)", "(1 2)", "(0 2)", "(0 1 2)", "(0 2 1)"].map(|cycles| cycles.parse::<Permutation>().unwrap());
+        // the sum of all group elements is central and squares to `|G|` times itself
+        let total = S3::new(elements.iter().map(|element| (element.clone(), 1)));
+        assert_eq!(multi(total.clone(), total.clone()), multi(S3::term(Permutation::identity(), 6), total.clone()));
+        let transposition = S3::basis(elements[1].clone());
+        assert_eq!(multi(transposition.clone(), total.clone()), multi(total.clone(), transposition.clone()));
+        // the sign idempotent
+        let sign = S3::new(elements.iter().map(|element| (element.clone(), i32::from(element.sign()))));
+        assert_eq!(multi(sign.clone(), sign.clone()), multi(S3::term(Permutation::identity(), 6), sign.clone()));
+        let rotation = S3::basis(elements[4].clone());
+        assert_eq!(rotation.clone().involution(), S3::basis(elements[5].clone()));
+        assert_ne!(multi(rotation.clone(), transposition.clone()), multi(transposition, rotation));
+        let unit = GroupAlgebra::<Composition, Permutation, Ratio<i32>>::basis(elements[4].clone());
+        assert_eq!(unsigned::pow(unit, 3), one());
+    }
+}
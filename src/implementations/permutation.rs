@@ -0,0 +1,52 @@
+use crate::permutation::Permutation;
+use crate::structures::basics::{Associative, Invertible, Magma, WithIdentityElement};
+
+// `operation(a, b) = a ∘ b`, `b` is applied first
+pub struct Composition;
+
+impl Associative for Composition {}
+
+impl WithIdentityElement<Permutation> for Composition {
+    fn identity() -> Permutation { Permutation::identity() }
+}
+
+impl Magma<Permutation> for Composition {
+    fn operation(operand1: Permutation, operand2: Permutation) -> Permutation { operand1.compose(&operand2) }
+}
+
+impl Invertible<Permutation> for Composition {
+    fn inverse(operand: Permutation) -> Permutation { operand.inverse() }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use crate::cyclic::order;
+    use crate::implementations::permutation::Composition;
+    use crate::permutation::Permutation;
+    use crate::power::{group, monoid};
+    use crate::structures::group_like::Group;
+
+    fn supplier_group<G: Group<T>, T>() {}
+
+    // perfect out-shuffle of a deck of `size` cards
+    fn riffle(size: usize) -> Permutation {
+        Permutation::from_images((0..size).map(|card| if card < size / 2 { 2 * card } else { 2 * card - size + 1 }).collect()).unwrap()
+    }
+
+    #[test]
+    fn shuffles() {
+        supplier_group::<Composition, Permutation>();
+        let shuffle = riffle(52);
+        assert_eq!(shuffle.order(), BigUint::from(8u32));
+        assert_eq!(order::<Composition, _>(shuffle.clone(), 8), 8);
+        assert_eq!(monoid::pow::<Composition, _>(shuffle.clone(), 8), Permutation::identity());
+        assert_eq!(monoid::pow::<Composition, _>(shuffle.clone(), 10u64.pow(18) + 3), monoid::pow::<Composition, _>(shuffle.clone(), 3));
+        assert_eq!(group::pow::<Composition, _>(shuffle.clone(), -1), shuffle.inverse());
+        let deck = riffle(1000);
+        let power = monoid::pow::<Composition, _>(deck.clone(), u64::MAX);
+        let reduced = u64::MAX % u64::try_from(deck.order()).unwrap();
+        assert_eq!(power, monoid::pow::<Composition, _>(deck, reduced));
+    }
+}
@@ -0,0 +1,184 @@
+use std::marker::PhantomData;
+use crate::polynomial::convolution::Convolution;
+use crate::polynomial::Polynomial;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::ring_like::{one, Semiring, unary_minus};
+
+pub struct PolynomialSum<T: Semiring>(PhantomData<T>);
+
+impl<T: Semiring> Commutative for PolynomialSum<T> where <T as Semiring>::Sum: Commutative {}
+
+impl<T: Semiring> Associative for PolynomialSum<T> where <T as Semiring>::Sum: Associative {}
+
+impl<T: Semiring> WithIdentityElement<Polynomial<T>> for PolynomialSum<T> where T: PartialEq {
+    fn identity() -> Polynomial<T> {
+        Polynomial::new(vec![])
+    }
+}
+
+impl<T: Semiring + Clone + PartialEq> Magma<Polynomial<T>> for PolynomialSum<T> {
+    fn operation(operand1: Polynomial<T>, operand2: Polynomial<T>) -> Polynomial<T> {
+        operand1 + operand2
+    }
+}
+
+impl<T: Semiring + PartialEq> Invertible<Polynomial<T>> for PolynomialSum<T> where T::Sum: Invertible<T> {
+    fn inverse(operand: Polynomial<T>) -> Polynomial<T> {
+        Polynomial::new(operand.into_coefficients().into_iter().map(unary_minus).collect())
+    }
+}
+
+
+pub struct PolynomialMul<T: Semiring>(PhantomData<T>);
+
+impl<T: Convolution + PartialEq> Semiring for Polynomial<T> {
+    type Sum = PolynomialSum<T>;
+    type Multi = PolynomialMul<T>;
+}
+
+impl<T: Semiring> Commutative for PolynomialMul<T> where <T as Semiring>::Multi: Commutative {}
+
+impl<T: Semiring> Associative for PolynomialMul<T> where <T as Semiring>::Multi: Associative {}
+
+impl<T: Semiring + PartialEq> WithIdentityElement<Polynomial<T>> for PolynomialMul<T> where T::Multi: WithIdentityElement<T> {
+    fn identity() -> Polynomial<T> {
+        Polynomial::new(vec![one()])
+    }
+}
+
+impl<T: Convolution + PartialEq> Magma<Polynomial<T>> for PolynomialMul<T> {
+    fn operation(operand1: Polynomial<T>, operand2: Polynomial<T>) -> Polynomial<T> {
+        operand1 * operand2
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::num::Wrapping;
+    use num::{BigInt, Complex};
+    use num::rational::Ratio;
+    use crate::matrix;
+    use crate::modular::{Zmod, Zp};
+    use crate::polynomial::convolution::{convolve, karatsuba, karatsuba_or_transform, schoolbook, toom3};
+    use crate::polynomial::Polynomial;
+    use crate::polynomial::transform::{convolve_by_transform, inverse_transform, RootOfUnity, transform};
+    use crate::power::unsigned::pow;
+    use crate::structures::ring_like::{CommutativeRingWithOne, minus, multi, one, plus};
+
+    fn supplier_ring<T: CommutativeRingWithOne>() {}
+
+    // deterministic pseudo-random coefficients
+    fn sequence(length: usize, seed: u64) -> Vec<u64> {
+        let mut state = seed;
+        (0..length).map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            state >> 40
+        }).collect()
+    }
+
+    #[test]
+    fn arithmetic() {
+        supplier_ring::<Polynomial<i64>>();
+        let p = Polynomial::new(vec![1, 2, 0]);
+        let q = Polynomial::new(vec![-1, 0, 3]);
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(plus(p.clone(), q.clone()), Polynomial::new(vec![0, 2, 3]));
+        assert_eq!(minus(p.clone(), p.clone()), Polynomial::new(vec![]));
+        assert_eq!(multi(p.clone(), q.clone()), Polynomial::new(vec![-1, -2, 3, 6]));
+        assert_eq!(pow(p.clone(), 3).evaluate(2), 125);
+        assert_eq!(q.evaluate(2), 11);
+        assert_eq!(q.to_string(), "3x^2+0x+-1");
+    }
+
+    #[test]
+    fn number_theoretic_transform() {
+        type Z = Zmod<998244353>;
+        let values = sequence(16, 1).into_iter().map(Z::new).collect::<Vec<_>>();
+        let mut transformed = values.clone();
+        transform(&mut transformed).unwrap();
+        assert_ne!(transformed, values);
+        inverse_transform(&mut transformed).unwrap();
+        assert_eq!(transformed, values);
+
+        let a = sequence(300, 2).into_iter().map(Z::new).collect::<Vec<_>>();
+        let b = sequence(200, 3).into_iter().map(Z::new).collect::<Vec<_>>();
+        assert_eq!(convolve_by_transform(&a, &b), Some(schoolbook(&a, &b)));
+        assert_eq!(
+            multi(Polynomial::new(a.clone()), Polynomial::new(b.clone())),
+            Polynomial::new(schoolbook(&a, &b))
+        );
+        // 7 has no roots of unity of order 4, so this falls back to Karatsuba
+        let c = sequence(100, 4).into_iter().map(Zmod::<7>::new).collect::<Vec<_>>();
+        assert_eq!(karatsuba_or_transform(&c, &c), schoolbook(&c, &c));
+        // the composite `2³² + 1 = 641 · 6700417` has no usable roots of unity either
+        let d = sequence(100, 10).into_iter().map(Zmod::<{ (1 << 32) + 1 }>::new).collect::<Vec<_>>();
+        assert_eq!(multi(Polynomial::new(d.clone()), Polynomial::new(d.clone())), Polynomial::new(schoolbook(&d, &d)));
+        // the least quadratic non-residue modulo 48473881 is 67
+        let root = Zmod::<48473881>::root_of_unity(8).unwrap();
+        assert_eq!(pow(root, 4), -one::<Zmod<48473881>>());
+        let e = a.iter().map(|x| Zp::<998244353>::from_residue(*x)).collect::<Vec<_>>();
+        assert_eq!(convolve_by_transform(&e, &e), Some(schoolbook(&e, &e)));
+    }
+
+    #[test]
+    fn fast_fourier_transform() {
+        let a = sequence(100, 5).into_iter().map(|x| (x % 100) as f64).collect::<Vec<_>>();
+        let b = sequence(70, 6).into_iter().map(|x| (x % 100) as f64).collect::<Vec<_>>();
+        let complex = |values: &[f64]| values.iter().map(|&x| Complex::new(x, 0.0)).collect::<Vec<_>>();
+        let product = multi(Polynomial::new(complex(&a)), Polynomial::new(complex(&b)));
+        let expected = schoolbook(&a, &b);
+        assert_eq!(product.coefficients().len(), expected.len());
+        for (actual, expected) in product.coefficients().iter().zip(expected) {
+            assert!((actual - Complex::new(expected, 0.0)).norm() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn karatsuba_and_toom3() {
+        let signed = |length, seed| sequence(length, seed).into_iter().map(|x| x as i64 - (1 << 23)).collect::<Vec<_>>();
+        let (a, b) = (signed(700, 7), signed(450, 8));
+        assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b));
+        assert_eq!(toom3(&a, &b), schoolbook(&a, &b));
+        assert_eq!(toom3(&b[..200], &a), schoolbook(&b[..200], &a));
+        assert_eq!(multi(Polynomial::new(a.clone()), Polynomial::new(b.clone())), Polynomial::new(schoolbook(&a, &b)));
+
+        let big = |values: &[i64]| values.iter().map(|&x| BigInt::from(x) * BigInt::from(x) * BigInt::from(x)).collect::<Vec<_>>();
+        assert_eq!(toom3(&big(&a), &big(&b)), schoolbook(&big(&a), &big(&b)));
+        let ratio = |values: &[i64]| values.iter().map(|&x| Ratio::new(x, 7)).collect::<Vec<_>>();
+        let (a, b) = (ratio(&a[..250]), ratio(&b[..200]));
+        assert_eq!(toom3(&a, &b), schoolbook(&a, &b));
+    }
+
+    #[test]
+    fn fixed_width_without_overflow() {
+        // the product fits, but Toom-3 intermediates would overflow
+        let mut a = vec![0i32; 192];
+        a[128..].fill(i32::MAX / 2);
+        let mut b = vec![0i32; 192];
+        b[0] = 1;
+        assert_eq!(convolve(&a, &b), schoolbook(&a, &b));
+        let ratio = |values: &[i32]| values.iter().map(|&x| Ratio::from_integer(i64::from(x))).collect::<Vec<_>>();
+        assert_eq!(convolve(&ratio(&a), &ratio(&b)), schoolbook(&ratio(&a), &ratio(&b)));
+        assert_eq!(convolve(&[Ratio::new(1u8, 2)], &[Ratio::new(2, 3)]), vec![Ratio::new(1, 3)]);
+        let big = |values: &[i32]| values.iter().map(|&x| Ratio::from_integer(BigInt::from(x))).collect::<Vec<_>>();
+        assert_eq!(convolve(&big(&a), &big(&b)), schoolbook(&big(&a), &big(&b)));
+        let wide = |values: &[i32], shift| values.iter().map(|&x| i128::from(x) << shift).collect::<Vec<_>>();
+        assert_eq!(convolve(&wide(&a, 96), &wide(&b, 0)), schoolbook(&wide(&a, 96), &wide(&b, 0)));
+        // overflow panics in debug builds as for scalars, wrapping must be asked for
+        if cfg!(debug_assertions) {
+            assert!(std::panic::catch_unwind(|| convolve(&[i32::MAX], &[2])).is_err());
+        }
+        let wrapping = sequence(100, 11).into_iter().map(|x| Wrapping(x as u8)).collect::<Vec<_>>();
+        assert_eq!(convolve(&wrapping, &wrapping), schoolbook(&wrapping, &wrapping));
+    }
+
+    #[test]
+    fn non_commutative_karatsuba() {
+        let entries = sequence(320, 9).into_iter().map(|x| (x % 19) as i64 - 9).collect::<Vec<_>>();
+        let matrices = entries.chunks(4).map(|m| matrix![m[0], m[1]; m[2], m[3]]).collect::<Vec<_>>();
+        let (a, b) = matrices.split_at(40);
+        assert_eq!(karatsuba(a, b), schoolbook(a, b));
+        assert_ne!(schoolbook(a, b), schoolbook(b, a));
+    }
+}
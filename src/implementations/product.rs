@@ -0,0 +1,157 @@
+use std::marker::PhantomData;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, TryInvertible, WithIdentityElement};
+use crate::structures::ring_like::Semiring;
+
+// direct products: a tuple or an array of structures acts componentwise on the tuple or array of their sets
+
+// the componentwise multiplication of a product of semirings, which is never a group:
+// `(1, 0)` is no zero but has no inverse, so only units, i.e. tuples of units, are invertible
+pub struct ProductMulti<G>(PhantomData<G>);
+
+macro_rules! tuple_impls {
+    ($(($t:ident, $g:ident, $index:tt)),+) => {
+impl<$($t, $g: Magma<$t>),+> Magma<($($t,)+)> for ($($g,)+) {
+    fn operation(operand1: ($($t,)+), operand2: ($($t,)+)) -> ($($t,)+) {
+        ($($g::operation(operand1.$index, operand2.$index),)+)
+    }
+}
+
+impl<$($g: Associative),+> Associative for ($($g,)+) {}
+
+impl<$($g: Commutative),+> Commutative for ($($g,)+) {}
+
+impl<$($t, $g: WithIdentityElement<$t>),+> WithIdentityElement<($($t,)+)> for ($($g,)+) {
+    fn identity() -> ($($t,)+) { ($($g::identity(),)+) }
+}
+
+impl<$($t, $g: Invertible<$t>),+> Invertible<($($t,)+)> for ($($g,)+) {
+    fn inverse(operand: ($($t,)+)) -> ($($t,)+) { ($($g::inverse(operand.$index),)+) }
+}
+
+impl<$($t, $g: Magma<$t>),+> Magma<($($t,)+)> for ProductMulti<($($g,)+)> {
+    fn operation(operand1: ($($t,)+), operand2: ($($t,)+)) -> ($($t,)+) { <($($g,)+)>::operation(operand1, operand2) }
+}
+
+impl<$($g: Associative),+> Associative for ProductMulti<($($g,)+)> {}
+
+impl<$($g: Commutative),+> Commutative for ProductMulti<($($g,)+)> {}
+
+impl<$($t, $g: WithIdentityElement<$t>),+> WithIdentityElement<($($t,)+)> for ProductMulti<($($g,)+)> {
+    fn identity() -> ($($t,)+) { <($($g,)+)>::identity() }
+}
+
+impl<$($t, $g: TryInvertible<$t>),+> TryInvertible<($($t,)+)> for ProductMulti<($($g,)+)> {
+    fn try_inverse(operand: ($($t,)+)) -> Option<($($t,)+)> { Some(($($g::try_inverse(operand.$index)?,)+)) }
+}
+
+impl<$($t: Semiring),+> Semiring for ($($t,)+) {
+    type Sum = ($($t::Sum,)+);
+    type Multi = ProductMulti<($($t::Multi,)+)>;
+}
+    };
+}
+
+tuple_impls!((T1, G1, 0), (T2, G2, 1));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2), (T4, G4, 3));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2), (T4, G4, 3), (T5, G5, 4));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2), (T4, G4, 3), (T5, G5, 4), (T6, G6, 5));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2), (T4, G4, 3), (T5, G5, 4), (T6, G6, 5), (T7, G7, 6));
+tuple_impls!((T1, G1, 0), (T2, G2, 1), (T3, G3, 2), (T4, G4, 3), (T5, G5, 4), (T6, G6, 5), (T7, G7, 6), (T8, G8, 7));
+
+
+impl<T, G: Magma<T>, const N: usize> Magma<[T; N]> for [G; N] {
+    fn operation(operand1: [T; N], operand2: [T; N]) -> [T; N] {
+        let mut operand2 = operand2.into_iter();
+        operand1.map(|x| G::operation(x, operand2.next().unwrap()))
+    }
+}
+
+impl<G: Associative, const N: usize> Associative for [G; N] {}
+
+impl<G: Commutative, const N: usize> Commutative for [G; N] {}
+
+impl<T, G: WithIdentityElement<T>, const N: usize> WithIdentityElement<[T; N]> for [G; N] {
+    fn identity() -> [T; N] { std::array::from_fn(|_| G::identity()) }
+}
+
+impl<T, G: Invertible<T>, const N: usize> Invertible<[T; N]> for [G; N] {
+    fn inverse(operand: [T; N]) -> [T; N] { operand.map(G::inverse) }
+}
+
+impl<T, G: Magma<T>, const N: usize> Magma<[T; N]> for ProductMulti<[G; N]> {
+    fn operation(operand1: [T; N], operand2: [T; N]) -> [T; N] { <[G; N]>::operation(operand1, operand2) }
+}
+
+impl<G: Associative, const N: usize> Associative for ProductMulti<[G; N]> {}
+
+impl<G: Commutative, const N: usize> Commutative for ProductMulti<[G; N]> {}
+
+impl<T, G: WithIdentityElement<T>, const N: usize> WithIdentityElement<[T; N]> for ProductMulti<[G; N]> {
+    fn identity() -> [T; N] { <[G; N]>::identity() }
+}
+
+impl<T, G: TryInvertible<T>, const N: usize> TryInvertible<[T; N]> for ProductMulti<[G; N]> {
+    fn try_inverse(operand: [T; N]) -> Option<[T; N]> {
+        let inverses = operand.into_iter().map(G::try_inverse).collect::<Option<Vec<_>>>()?;
+        inverses.try_into().ok()
+    }
+}
+
+impl<T: Semiring, const N: usize> Semiring for [T; N] {
+    type Sum = [T::Sum; N];
+    type Multi = ProductMulti<[T::Multi; N]>;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::rational::Ratio;
+    use crate::crt::crt;
+    use crate::fibonacci::fibonacci;
+    use crate::modular::Zmod;
+    use crate::structures::group_like::AbelGroup;
+    use crate::structures::ring_like::{CommutativeRingWithOne, minus, multi, one, plus, RingWithOne, Semiring, SemiringWithOne, try_inverse, UnitDetection};
+
+    fn supplier_semiring<T: SemiringWithOne>() {}
+
+    fn supplier_ring<T: RingWithOne>() {}
+
+    fn supplier_commutative_ring<T: CommutativeRingWithOne>() {}
+
+    fn supplier_unit_detection<T: UnitDetection>() {}
+
+    fn supplier_abel_group<G: AbelGroup<T>, T>() {}
+
+    #[test]
+    fn structures() {
+        supplier_semiring::<(u8, u16)>();
+        supplier_ring::<(i8, i16, i32, i64, Zmod<3>, isize, BigInt, Ratio<i32>)>();
+        supplier_commutative_ring::<(Zmod<7>, f64)>();
+        supplier_semiring::<[u32; 3]>();
+        supplier_ring::<[(i32, Zmod<5>); 2]>();
+        supplier_unit_detection::<(f64, Ratio<i32>)>();
+        supplier_abel_group::<<[i64; 4] as Semiring>::Sum, [i64; 4]>();
+    }
+
+    #[test]
+    fn componentwise() {
+        assert_eq!(plus((1, 2.5), (3, 0.5)), (4, 3.0));
+        assert_eq!(multi((2, Zmod::<5>::new(3)), (3, Zmod::new(4))), (6, Zmod::new(2)));
+        assert_eq!(minus([1, 2, 3], [3, 2, 1]), [-2, 0, 2]);
+        assert_eq!(one::<[i8; 2]>(), [1, 1]);
+        assert_eq!(try_inverse((2.0, Ratio::new(1, 3))), Some((0.5, Ratio::from_integer(3))));
+        assert_eq!(try_inverse((1.0, Ratio::from_integer(0))), None);
+        assert_eq!(try_inverse([Zmod::<12>::new(5), Zmod::new(7)]), Some([Zmod::new(5), Zmod::new(7)]));
+    }
+
+    #[test]
+    fn simultaneous_residues() {
+        const P1: u64 = 4611686018427387847;
+        const P2: u64 = 4611686018427387817;
+        let (r1, r2) = fibonacci::<(Zmod<P1>, Zmod<P2>)>(150);
+        let (value, _) = crt([(BigInt::from(r1.value()), BigInt::from(P1)), (BigInt::from(r2.value()), BigInt::from(P2))]).unwrap();
+        assert_eq!(value, fibonacci::<BigInt>(150));
+    }
+}
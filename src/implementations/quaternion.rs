@@ -0,0 +1,166 @@
+use std::marker::PhantomData;
+use num::Integer;
+use num::rational::Ratio;
+use crate::quaternion::Quaternion;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, TryInvertible, WithIdentityElement};
+use crate::structures::ring_like::{CommutativeRing, Field, minus, multi, one, plus, reciprocal, Ring, Semiring, try_inverse, unary_minus, UnitDetection, zero};
+
+pub struct QuaternionSum<T: Semiring>(PhantomData<T>);
+
+impl<T: Semiring> Commutative for QuaternionSum<T> where <T as Semiring>::Sum: Commutative {}
+
+impl<T: Semiring> Associative for QuaternionSum<T> where <T as Semiring>::Sum: Associative {}
+
+impl<T: Semiring> WithIdentityElement<Quaternion<T>> for QuaternionSum<T> {
+    fn identity() -> Quaternion<T> {
+        Quaternion::new(zero(), zero(), zero(), zero())
+    }
+}
+
+impl<T: Semiring> Magma<Quaternion<T>> for QuaternionSum<T> {
+    fn operation(operand1: Quaternion<T>, operand2: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            plus(operand1.re, operand2.re),
+            plus(operand1.i, operand2.i),
+            plus(operand1.j, operand2.j),
+            plus(operand1.k, operand2.k),
+        )
+    }
+}
+
+impl<T: Semiring> Invertible<Quaternion<T>> for QuaternionSum<T> where T::Sum: Invertible<T> {
+    fn inverse(operand: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(T::Sum::inverse(operand.re), T::Sum::inverse(operand.i), T::Sum::inverse(operand.j), T::Sum::inverse(operand.k))
+    }
+}
+
+
+pub struct QuaternionMul<T: Semiring>(PhantomData<T>);
+
+impl<T: CommutativeRing + Clone> Semiring for Quaternion<T> {
+    type Sum = QuaternionSum<T>;
+    type Multi = QuaternionMul<T>;
+}
+
+// the Hamilton product is associative only over commutative coefficients
+impl<T: Semiring> Associative for QuaternionMul<T> where <T as Semiring>::Multi: Associative + Commutative {}
+
+impl<T: Semiring> WithIdentityElement<Quaternion<T>> for QuaternionMul<T> where T::Multi: WithIdentityElement<T> {
+    fn identity() -> Quaternion<T> {
+        Quaternion::new(one(), zero(), zero(), zero())
+    }
+}
+
+impl<T: Ring + Clone> Magma<Quaternion<T>> for QuaternionMul<T> {
+    fn operation(operand1: Quaternion<T>, operand2: Quaternion<T>) -> Quaternion<T> {
+        let Quaternion { re: a1, i: b1, j: c1, k: d1 } = operand1;
+        let Quaternion { re: a2, i: b2, j: c2, k: d2 } = operand2;
+        let m = |x: &T, y: &T| multi(x.clone(), y.clone());
+        Quaternion::new(
+            minus(minus(minus(m(&a1, &a2), m(&b1, &b2)), m(&c1, &c2)), m(&d1, &d2)),
+            minus(plus(plus(m(&a1, &b2), m(&b1, &a2)), m(&c1, &d2)), m(&d1, &c2)),
+            plus(plus(minus(m(&a1, &c2), m(&b1, &d2)), m(&c1, &a2)), m(&d1, &b2)),
+            plus(minus(plus(m(&a1, &d2), m(&b1, &c2)), m(&c1, &b2)), m(&d1, &a2)),
+        )
+    }
+}
+
+fn norm<T: Ring + Clone>(operand: &Quaternion<T>) -> T {
+    let square = |x: &T| multi(x.clone(), x.clone());
+    plus(plus(square(&operand.re), square(&operand.i)), plus(square(&operand.j), square(&operand.k)))
+}
+
+fn scaled_conjugate<T: Ring + Clone>(operand: Quaternion<T>, scale: T) -> Quaternion<T> {
+    let m = |x: T| multi(x, scale.clone());
+    Quaternion::new(m(operand.re), m(unary_minus(operand.i)), m(unary_minus(operand.j)), m(unary_minus(operand.k)))
+}
+
+// `q⁻¹ = q̄ / |q|²`, only for real fields: over `ℂ` or finite fields non-zero quaternions like `1 + i·i` have norm zero
+fn conjugate_over_norm<T: Field + Clone>(operand: Quaternion<T>) -> Quaternion<T> {
+    let scale = reciprocal(norm(&operand));
+    scaled_conjugate(operand, scale)
+}
+
+macro_rules! real_inverse {
+    ($($t:ty),+) => {$(
+impl Invertible<Quaternion<$t>> for QuaternionMul<$t> {
+    fn inverse(operand: Quaternion<$t>) -> Quaternion<$t> { conjugate_over_norm(operand) }
+}
+    )+};
+}
+
+real_inverse!(f32, f64);
+
+impl<T: Integer + Clone> Invertible<Quaternion<Ratio<T>>> for QuaternionMul<Ratio<T>> where Ratio<T>: Field {
+    fn inverse(operand: Quaternion<Ratio<T>>) -> Quaternion<Ratio<T>> { conjugate_over_norm(operand) }
+}
+
+// units are exactly the quaternions whose norm is a unit, so Lipschitz integers have the units `±1`, `±i`, `±j`, `±k`
+impl<T: CommutativeRing + UnitDetection + Clone> TryInvertible<Quaternion<T>> for QuaternionMul<T> {
+    fn try_inverse(operand: Quaternion<T>) -> Option<Quaternion<T>> {
+        let scale = try_inverse(norm(&operand))?;
+        Some(scaled_conjugate(operand, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+    use crate::power::signed;
+    use crate::quaternion::Quaternion;
+    use num::rational::Ratio;
+    use crate::modular::Zmod;
+    use crate::structures::ring_like::{DivisionRing, left_div, multi, one, right_div, RingWithOne, try_inverse, zero};
+
+    fn supplier_division_ring<T: DivisionRing>() {}
+
+    fn supplier_ring<T: RingWithOne>() {}
+
+    #[test]
+    fn hamilton_product() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let k = Quaternion::new(0, 0, 0, 1);
+        assert_eq!(multi(i, j), k);
+        assert_eq!(multi(j, i), -k);
+        assert_eq!(multi(multi(i, j), k), -one::<Quaternion<i32>>());
+        assert_eq!(i * j, k);
+    }
+
+    #[test]
+    fn division() {
+        supplier_division_ring::<Quaternion<f64>>();
+        supplier_division_ring::<Quaternion<Ratio<i64>>>();
+        let p = Quaternion::new(1.0, 2.0, 0.0, -1.0);
+        let q = Quaternion::new(0.0, 1.0, 1.0, 0.0);
+        assert_eq!(multi(q, left_div(multi(q, p), q)), multi(q, p));
+        assert_eq!(right_div(multi(p, q), q), p);
+        assert_eq!(signed::pow(q, -2), Quaternion::new(-0.5, 0.0, 0.0, 0.0));
+        let r = Quaternion::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(multi(signed::pow(r, -3), signed::pow(r, 3)), one());
+    }
+
+    #[test]
+    fn zero_divisors_over_finite_fields() {
+        supplier_ring::<Quaternion<Zmod<5>>>();
+        let z = |x| Zmod::<5>::from_signed(x);
+        let p = Quaternion::new(z(1), z(2), z(0), z(0));
+        let conjugate = Quaternion::new(z(1), z(-2), z(0), z(0));
+        assert_eq!(multi(p, conjugate), zero());
+        assert_eq!(try_inverse(p), None);
+        assert_eq!(try_inverse(Quaternion::new(z(1), z(1), z(0), z(0))), Some(Quaternion::new(z(3), z(-3), z(0), z(0))));
+        assert_eq!(try_inverse(Quaternion::new(0, 0, -1, 0)), Some(Quaternion::new(0, 0, 1, 0)));
+        assert_eq!(try_inverse(Quaternion::new(1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn matrix_entries() {
+        let i = Quaternion::new(0, 1, 0, 0);
+        let j = Quaternion::new(0, 0, 1, 0);
+        let zero = Quaternion::default();
+        assert_eq!(
+            multi(matrix![i, zero; zero, j], matrix![j, zero; zero, i]),
+            matrix![Quaternion::new(0, 0, 0, 1), zero; zero, Quaternion::new(0, 0, 0, -1)]
+        );
+    }
+}
@@ -1,5 +1,4 @@
 #![feature(trait_alias)]
-#![feature(associated_type_bounds)]
 // #![feature(adt_const_params)]
 
 extern crate core;
@@ -7,8 +6,20 @@ extern crate core;
 pub mod structures;
 pub mod implementations;
 pub mod power;
+pub mod primality;
 pub mod matrix;
 pub mod fibonacci;
+pub mod quaternion;
+pub mod dual;
+pub mod modular;
+pub mod checked;
+pub mod galois;
+pub mod reed_solomon;
+pub mod polynomial;
+pub mod crt;
+pub mod cyclic;
+pub mod permutation;
+pub mod monoid_algebra;
 
 #[cfg(test)]
 mod tests {
@@ -1,19 +1,48 @@
 use std::fmt::{Display, Formatter};
 use std::iter::Sum;
 use std::ops::{Add, Index, Mul, Neg, Sub};
+use std::str::FromStr;
 pub use finite::FiniteMatrix;
 use Matrix::Finite;
 use crate::matrix::Matrix::InfiniteDiagonal;
-use crate::matrix::finite::Shape;
+use crate::matrix::finite::{ParseMatrixError, Shape};
 use crate::structures::ring_like::{Semiring, zero};
 
 pub mod finite;
+pub mod io;
+pub mod format;
+mod elimination;
 
 
 pub fn matrix<T>(height: usize, width: usize, flat_data: Vec<T>) -> Matrix<T> {
     Finite(FiniteMatrix::from_iter(Shape { height, width }, flat_data.into_iter()))
 }
 
+// `matrix![1, 2; 3, 4]` builds a finite matrix row by row, rows of different lengths fail to compile
+#[macro_export]
+macro_rules! matrix {
+    (@unit $element:expr) => { () };
+    () => {
+        $crate::matrix::Matrix::Finite($crate::matrix::FiniteMatrix::from_iter(
+            $crate::matrix::finite::Shape::empty(), ::std::iter::empty(),
+        ))
+    };
+    ($($($element:expr),+ $(,)?);+ $(;)?) => {{
+        const WIDTHS: &[usize] = &[$(<[()]>::len(&[$($crate::matrix!(@unit $element)),+])),+];
+        const _: () = {
+            let mut row = 1;
+            while row < WIDTHS.len() {
+                assert!(WIDTHS[row] == WIDTHS[0], "all rows of a matrix must have the same length");
+                row += 1;
+            }
+        };
+        $crate::matrix::Matrix::Finite($crate::matrix::FiniteMatrix::from_iter(
+            $crate::matrix::finite::Shape { height: WIDTHS.len(), width: WIDTHS[0] },
+            ::std::vec![$($($element),+),+].into_iter(),
+        ))
+    }};
+}
+
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
 pub enum Matrix<T> {
     InfiniteDiagonal(T),
@@ -23,12 +52,29 @@ pub enum Matrix<T> {
 impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            InfiniteDiagonal(value) => writeln!(f, "Diagonal {{{value}}}"),
+            InfiniteDiagonal(value) => match f.precision() {
+                Some(precision) => writeln!(f, "Diagonal {{{value:.precision$}}}"),
+                None => writeln!(f, "Diagonal {{{value}}}"),
+            }
             Finite(regular) => regular.fmt(f)
         }
     }
 }
 
+impl<T: FromStr> FromStr for Matrix<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.strip_prefix("Diagonal {").and_then(|s| s.strip_suffix('}')) {
+            Some(value) => value.parse()
+                .map(InfiniteDiagonal)
+                .map_err(|_| ParseMatrixError::Element(value.to_owned())),
+            None => s.parse().map(Finite),
+        }
+    }
+}
+
 macro_rules! impl_standard {
     ($trait:tt, $function:tt, $operator:tt) => {
 
@@ -89,9 +135,9 @@ impl<T: Mul<Output=T> + Clone + Sum> Mul for Matrix<T> {
 
 impl <T: Semiring + Clone> Matrix<T> {
     pub fn get(&self, index: (usize, usize)) -> T {
-        match &self {
-            &InfiniteDiagonal(d) => if index.0 == index.1 { d.to_owned() } else { zero() },
-            &Finite(m) => m.index(index).to_owned()
+        match self {
+            InfiniteDiagonal(d) => if index.0 == index.1 { d.to_owned() } else { zero() },
+            Finite(m) => m.index(index).to_owned()
         }
     }
 }
@@ -100,44 +146,41 @@ impl <T: Semiring + Clone> Matrix<T> {
 #[cfg(test)]
 mod tests {
     use crate::fibonacci::fibonacci;
-    use crate::matrix::matrix;
+    use crate::matrix::Matrix;
+    use crate::matrix::Matrix::InfiniteDiagonal;
     use crate::structures::ring_like::{minus, multi, plus, unary_minus};
 
     #[test]
     fn test_multiplication() {
-        let matrix1 = matrix(3, 2, vec![1, 2, 3, 4, 5, 6]);
-        let matrix2 = matrix(2, 3, vec![7, 8, 9, 10, 11, 12]);
-        let correct_data = vec![27, 30, 33, 61, 68, 75, 95, 106, 117];
-        let correct_data = matrix(3, 3, correct_data);
+        let matrix1 = matrix![1, 2; 3, 4; 5, 6];
+        let matrix2 = matrix![7, 8, 9; 10, 11, 12];
+        let correct_data = matrix![27, 30, 33; 61, 68, 75; 95, 106, 117];
         assert_eq!(matrix1.clone() * matrix2.clone(), correct_data);
         assert_eq!(multi(matrix1, matrix2), correct_data);
     }
 
     #[test]
     fn test_addition() {
-        let matrix1 = matrix(3, 2, vec![1, 2, 3, 4, 5, 6]);
-        let matrix2 = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
-        let correct_data = vec![8, 10, 12, 14, 16, 18];
-        let correct_data = matrix(3, 2, correct_data);
+        let matrix1 = matrix![1, 2; 3, 4; 5, 6];
+        let matrix2 = matrix![7, 8; 9, 10; 11, 12];
+        let correct_data = matrix![8, 10; 12, 14; 16, 18];
         assert_eq!(matrix1.clone() + matrix2.clone(), correct_data);
         assert_eq!(plus(matrix1, matrix2), correct_data);
     }
 
     #[test]
     fn test_negation() {
-        let matrix1 = matrix(3, 2, vec![1, 2, 3, 4, 5, 6]);
-        let correct_data = vec![-1, -2, -3, -4, -5, -6];
-        let correct_data = matrix(3, 2, correct_data);
+        let matrix1 = matrix![1, 2; 3, 4; 5, 6];
+        let correct_data = matrix![-1, -2; -3, -4; -5, -6];
         assert_eq!(-matrix1.clone(), correct_data);
         assert_eq!(unary_minus(matrix1), correct_data);
     }
 
     #[test]
     fn test_subtraction() {
-        let matrix1 = matrix(3, 2, vec![1, 2, 3, 4, 5, 6]);
-        let matrix2 = matrix(3, 2, vec![7, 8, 9, 10, 11, 12]);
-        let correct_data = vec![-6, -6, -6, -6, -6, -6];
-        let correct_data = matrix(3, 2, correct_data);
+        let matrix1 = matrix![1, 2; 3, 4; 5, 6];
+        let matrix2 = matrix![7, 8; 9, 10; 11, 12];
+        let correct_data = matrix![-6, -6; -6, -6; -6, -6];
         assert_eq!(matrix1.clone() - matrix2.clone(), correct_data);
         assert_eq!(minus(matrix1, matrix2), correct_data);
     }
@@ -152,4 +195,15 @@ mod tests {
         assert_eq!(fibonacci::<i32>(5), 5);
         assert_eq!(fibonacci::<i32>(6), 8);
     }
+
+    #[test]
+    fn test_parse() {
+        let finite = matrix![1, 2; 3, 4];
+        assert_eq!(finite.to_string().parse(), Ok(finite.clone()));
+        assert_eq!("[[1, 2], [3, 4]]".parse(), Ok(finite));
+        assert_eq!(InfiniteDiagonal(5).to_string().parse(), Ok(InfiniteDiagonal(5)));
+        assert!("Diagonal {x}".parse::<Matrix<i32>>().is_err());
+        assert_eq!(matrix![1, 2, 3], "1 2 3".parse().unwrap());
+        assert_eq!(matrix![], "".parse::<Matrix<i32>>().unwrap());
+    }
 }
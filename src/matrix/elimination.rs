@@ -0,0 +1,131 @@
+use crate::matrix::FiniteMatrix;
+use crate::matrix::finite::Shape;
+use crate::structures::ring_like::{Field, minus, multi, one, reciprocal, unary_minus, zero};
+
+// Gauss–Jordan elimination of the first `columns` columns, returns the pivot columns
+fn reduce<T: Field + Clone + PartialEq>(rows: &mut [Vec<T>], columns: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&r| rows[r][column] != zero()) else { continue };
+        rows.swap(row, pivot);
+        let scale = reciprocal(rows[row][column].clone());
+        for item in rows[row].iter_mut() {
+            *item = multi(item.clone(), scale.clone());
+        }
+        let pivot_row = rows[row].clone();
+        for (other, values) in rows.iter_mut().enumerate() {
+            if other == row || values[column] == zero() {
+                continue;
+            }
+            let factor = values[column].clone();
+            for (item, pivot_item) in values.iter_mut().zip(&pivot_row) {
+                *item = minus(item.clone(), multi(factor.clone(), pivot_item.clone()));
+            }
+        }
+        pivots.push(column);
+    }
+    pivots
+}
+
+impl<T: Field + Clone + PartialEq> FiniteMatrix<T> {
+    fn augmented_rows(&self, extra: impl Fn(usize) -> Vec<T>) -> Vec<Vec<T>> {
+        (0..self.height())
+            .map(|row| (0..self.width()).map(|column| self[(row, column)].clone()).chain(extra(row)).collect())
+            .collect()
+    }
+
+    // `None` for non-square or singular matrices
+    pub fn inverse(&self) -> Option<FiniteMatrix<T>> {
+        let size = self.height();
+        if size != self.width() {
+            return None;
+        }
+        let mut rows = self.augmented_rows(|row| (0..size).map(|column| if row == column { one() } else { zero() }).collect());
+        if reduce(&mut rows, size).len() < size {
+            return None;
+        }
+        let shape = Shape { height: size, width: size };
+        Some(FiniteMatrix::from_iter(shape, rows.into_iter().flat_map(|row| row.into_iter().skip(size))))
+    }
+
+    // panics for non-square matrices
+    pub fn determinant(&self) -> T {
+        assert_eq!(self.height(), self.width(), "Determinant of a non-square matrix");
+        let size = self.height();
+        let mut rows = self.augmented_rows(|_| vec![]);
+        let mut determinant = one::<T>();
+        for column in 0..size {
+            let Some(pivot) = (column..size).find(|&r| rows[r][column] != zero()) else { return zero() };
+            if pivot != column {
+                rows.swap(column, pivot);
+                determinant = unary_minus(determinant);
+            }
+            determinant = multi(determinant, rows[column][column].clone());
+            let scale = reciprocal(rows[column][column].clone());
+            let pivot_row = rows[column].clone();
+            for values in rows[column + 1..].iter_mut() {
+                let factor = multi(values[column].clone(), scale.clone());
+                for (item, pivot_item) in values.iter_mut().zip(&pivot_row) {
+                    *item = minus(item.clone(), multi(factor.clone(), pivot_item.clone()));
+                }
+            }
+        }
+        determinant
+    }
+
+    // some `x` with `self · x = rhs`, free variables are set to zero, `None` if there is no solution
+    pub fn solve(&self, rhs: &[T]) -> Option<Vec<T>> {
+        assert_eq!(self.height(), rhs.len());
+        let width = self.width();
+        let mut rows = self.augmented_rows(|row| vec![rhs[row].clone()]);
+        let pivots = reduce(&mut rows, width);
+        if rows[pivots.len()..].iter().any(|row| row[width] != zero()) {
+            return None;
+        }
+        let mut solution = vec![zero(); width];
+        for (row, column) in pivots.into_iter().enumerate() {
+            solution[column] = rows[row][width].clone();
+        }
+        Some(solution)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::rational::Ratio;
+    use crate::matrix::FiniteMatrix;
+    use crate::matrix::Matrix::Finite;
+    use crate::matrix::finite::Shape;
+    use crate::structures::ring_like::multi;
+
+    fn rational_matrix(height: usize, width: usize, data: &[i64]) -> FiniteMatrix<Ratio<i64>> {
+        FiniteMatrix::from_iter(Shape { height, width }, data.iter().map(|&x| Ratio::from_integer(x)))
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = rational_matrix(3, 3, &[2, 1, 0, 0, 1, 3, 1, 0, 1]);
+        let inverse = matrix.inverse().unwrap();
+        let identity = rational_matrix(3, 3, &[1, 0, 0, 0, 1, 0, 0, 0, 1]);
+        assert_eq!(multi(Finite(matrix), Finite(inverse)), Finite(identity));
+        assert_eq!(rational_matrix(2, 2, &[1, 2, 2, 4]).inverse(), None);
+        assert_eq!(rational_matrix(2, 3, &[1, 2, 3, 4, 5, 6]).inverse(), None);
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(rational_matrix(3, 3, &[0, 1, 2, 1, 0, 3, 4, -3, 8]).determinant(), Ratio::from_integer(-2));
+        assert_eq!(rational_matrix(2, 2, &[1, 2, 2, 4]).determinant(), Ratio::from_integer(0));
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = rational_matrix(2, 3, &[1, 1, 1, 0, 1, 2]);
+        let solution = matrix.solve(&[Ratio::from_integer(6), Ratio::from_integer(8)]).unwrap();
+        assert_eq!(solution, vec![Ratio::from_integer(-2), Ratio::from_integer(8), Ratio::from_integer(0)]);
+        let inconsistent = rational_matrix(2, 2, &[1, 1, 2, 2]);
+        assert_eq!(inconsistent.solve(&[Ratio::from_integer(1), Ratio::from_integer(3)]), None);
+    }
+}
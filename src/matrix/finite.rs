@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
 use std::iter::{Sum, zip};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
 pub struct Shape {
@@ -49,8 +50,7 @@ impl<T> FiniteMatrix<T> {
         assert!(index.0 < self.height());
         let width = self.width();
         assert!(index.1 < width);
-        let flat_index = width * index.0 + index.1;
-        flat_index
+        width * index.0 + index.1
     }
 
     pub fn iter(&self) -> impl Iterator<Item=&T> { self.storage.iter() }
@@ -73,7 +73,7 @@ impl<T> FiniteMatrix<T> {
     }
 
     pub fn into_iter_with_indexes(self) -> impl Iterator<Item=((usize, usize), T)> {
-        zip(self.indexes(), self.into_iter())
+        zip(self.indexes(), self)
     }
 
     pub fn map<R>(self, f: impl Fn(T) -> R) -> FiniteMatrix<R> {
@@ -134,10 +134,13 @@ impl<T: Display> Display for FiniteMatrix<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut column = 0;
         for element in self.iter() {
-            write!(f, "{}", element)?;
+            match f.precision() {
+                Some(precision) => write!(f, "{element:.precision$}")?,
+                None => write!(f, "{element}")?,
+            }
             column += 1;
             if column == self.width() {
-                write!(f, "\n")?;
+                writeln!(f)?;
                 column = 0;
             } else {
                 write!(f, " ")?;
@@ -148,6 +151,81 @@ impl<T: Display> Display for FiniteMatrix<T> {
 }
 
 
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub enum ParseMatrixError {
+    Syntax(String),
+    Element(String),
+    RaggedRows,
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMatrixError::Syntax(message) => write!(f, "invalid matrix syntax: {message}"),
+            ParseMatrixError::Element(element) => write!(f, "cannot parse matrix element `{element}`"),
+            ParseMatrixError::RaggedRows => write!(f, "matrix rows have different lengths"),
+        }
+    }
+}
+
+impl std::error::Error for ParseMatrixError {}
+
+fn split_bracketed_rows(s: &str) -> Result<Vec<&str>, ParseMatrixError> {
+    let inner = s.strip_prefix('[').and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| ParseMatrixError::Syntax("unbalanced brackets".to_owned()))?;
+    let mut rows = Vec::new();
+    let mut rest = inner.trim();
+    while !rest.is_empty() {
+        let row_end = rest.strip_prefix('[').and_then(|row| row.find(']'))
+            .ok_or_else(|| ParseMatrixError::Syntax(format!("expected a row in brackets at `{rest}`")))?;
+        rows.push(&rest[1..=row_end]);
+        rest = rest[row_end + 2..].trim_start();
+        rest = match rest.strip_prefix(',') {
+            Some(after_comma) => after_comma.trim_start(),
+            None if rest.is_empty() => rest,
+            None => return Err(ParseMatrixError::Syntax(format!("expected `,` at `{rest}`"))),
+        };
+    }
+    Ok(rows)
+}
+
+// `[]` is a row without elements, empty elements like in `[1,,2]` are rejected
+fn split_bracketed_row(row: &str) -> Result<Vec<&str>, ParseMatrixError> {
+    let row = row.trim();
+    if row.is_empty() {
+        return Ok(vec![]);
+    }
+    row.split(',').map(str::trim)
+        .map(|item| if item.is_empty() { Err(ParseMatrixError::Syntax(format!("empty element in `[{row}]`"))) } else { Ok(item) })
+        .collect()
+}
+
+// accepts both the `Display` form (whitespace-separated rows on separate lines) and `[[1, 2], [3, 4]]`
+impl<T: FromStr> FromStr for FiniteMatrix<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let rows: Vec<Vec<&str>> = if s.starts_with('[') {
+            split_bracketed_rows(s)?.into_iter()
+                .map(split_bracketed_row)
+                .collect::<Result<_, _>>()?
+        } else {
+            s.lines().map(|row| row.split_whitespace().collect()).collect()
+        };
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(ParseMatrixError::RaggedRows);
+        }
+        let shape = Shape { height: rows.len(), width };
+        let storage = rows.into_iter().flatten()
+            .map(|item| item.parse().map_err(|_| ParseMatrixError::Element(item.to_owned())))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(FiniteMatrix::from_iter(shape, storage.into_iter()))
+    }
+}
+
+
 impl<T> IntoIterator for FiniteMatrix<T> {
     type Item = T;
     type IntoIter = <Vec<T> as IntoIterator>::IntoIter;
@@ -164,7 +242,7 @@ impl<T: Add<T, Output=T>> Add for FiniteMatrix<T> {
     fn add(self, rhs: Self) -> Self::Output {
         let shape = self.shape;
         assert_eq!(shape, rhs.shape);
-        let element_wise_sum = self.into_iter().zip(rhs.into_iter()).map(|(a, b)| a + b);
+        let element_wise_sum = self.into_iter().zip(rhs).map(|(a, b)| a + b);
         FiniteMatrix::<T>::from_iter(shape, element_wise_sum)
     }
 }
@@ -175,7 +253,7 @@ impl<T: Sub<T, Output=T>> Sub for FiniteMatrix<T> {
     fn sub(self, rhs: Self) -> Self::Output {
         let shape = self.shape;
         assert_eq!(shape, rhs.shape);
-        let element_wise_sum = self.into_iter().zip(rhs.into_iter()).map(|(a, b)| a - b);
+        let element_wise_sum = self.into_iter().zip(rhs).map(|(a, b)| a - b);
         FiniteMatrix::<T>::from_iter(shape, element_wise_sum)
     }
 }
@@ -203,7 +281,7 @@ impl<T: Mul<Output=T> + Sum + Clone> Mul for FiniteMatrix<T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::matrix::finite::{FiniteMatrix, Shape};
+    use crate::matrix::finite::{FiniteMatrix, ParseMatrixError, Shape};
 
     #[test]
     fn print() {
@@ -226,4 +304,22 @@ mod tests {
             "0 1 2\n3 4 5\n"
         );
     }
+
+    #[test]
+    fn parse() {
+        let shape = Shape { height: 2, width: 3 };
+        let matrix = FiniteMatrix::<i32>::from_iter(shape, 0..6);
+        assert_eq!(matrix.to_string().parse(), Ok(matrix.clone()));
+        assert_eq!("[[0, 1, 2], [3, 4, 5]]".parse(), Ok(matrix.clone()));
+        assert_eq!("[[0,1,2],[3,4,5]]".parse(), Ok(matrix));
+        assert_eq!("".parse(), Ok(FiniteMatrix::<i32>::with_default(Shape::empty())));
+        assert_eq!("[]".parse(), Ok(FiniteMatrix::<i32>::with_default(Shape::empty())));
+        assert_eq!("1 2\n3\n".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::RaggedRows));
+        assert_eq!("[[1, x]]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::Element("x".to_owned())));
+        assert!(matches!("[[1, 2]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::Syntax(_))));
+        assert!(matches!("[[1, 2] [3, 4]]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::Syntax(_))));
+        assert!(matches!("[[1,,2]]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::Syntax(_))));
+        assert!(matches!("[[1, 2, ]]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::Syntax(_))));
+        assert_eq!("[[1, 2], []]".parse::<FiniteMatrix<i32>>(), Err(ParseMatrixError::RaggedRows));
+    }
 }
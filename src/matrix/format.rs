@@ -0,0 +1,280 @@
+use std::fmt::{Display, Formatter};
+use std::iter::once;
+use crate::matrix::{FiniteMatrix, Matrix};
+use crate::matrix::Matrix::{Finite, InfiniteDiagonal};
+use crate::structures::ring_like::Semiring;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum Alignment {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum Brackets {
+    None,
+    Square,
+    Round,
+    Vertical,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Cell {
+    Value(String),
+    HorizontalEllipsis,
+    VerticalEllipsis,
+    DiagonalEllipsis,
+}
+
+struct Ellipses {
+    horizontal: &'static str,
+    vertical: &'static str,
+    diagonal: &'static str,
+}
+
+const PLAIN: Ellipses = Ellipses { horizontal: "...", vertical: "...", diagonal: "..." };
+const UNICODE: Ellipses = Ellipses { horizontal: "⋯", vertical: "⋮", diagonal: "⋱" };
+const LATEX: Ellipses = Ellipses { horizontal: "\\cdots", vertical: "\\vdots", diagonal: "\\ddots" };
+
+// builder for aligned output, rows and columns beyond the maxima are elided, infinite diagonals show a `diagonal_size` corner
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct MatrixFormat {
+    alignment: Alignment,
+    precision: Option<usize>,
+    brackets: Brackets,
+    max_rows: Option<usize>,
+    max_columns: Option<usize>,
+    diagonal_size: usize,
+}
+
+impl Default for MatrixFormat {
+    fn default() -> Self {
+        MatrixFormat {
+            alignment: Alignment::Right,
+            precision: None,
+            brackets: Brackets::Square,
+            max_rows: None,
+            max_columns: None,
+            diagonal_size: 3,
+        }
+    }
+}
+
+pub trait ToCells {
+    fn to_cells(&self, format: &MatrixFormat, precision: Option<usize>) -> Vec<Vec<Cell>>;
+}
+
+fn format_value<T: Display>(value: &T, precision: Option<usize>) -> Cell {
+    Cell::Value(match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => format!("{value}"),
+    })
+}
+
+// `None` marks the place of the ellipsis
+fn visible(count: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if count > max.max(1) => {
+            let max = max.max(1);
+            let tail = max / 2;
+            let head = max - tail;
+            (0..head).map(Some).chain(once(None)).chain((count - tail..count).map(Some)).collect()
+        }
+        _ => (0..count).map(Some).collect(),
+    }
+}
+
+fn grid(rows: &[Option<usize>], columns: &[Option<usize>], mut value: impl FnMut(usize, usize) -> Cell) -> Vec<Vec<Cell>> {
+    rows.iter().map(|row| columns.iter().map(|column| match (row, column) {
+        (Some(row), Some(column)) => value(*row, *column),
+        (Some(_), None) => Cell::HorizontalEllipsis,
+        (None, Some(_)) => Cell::VerticalEllipsis,
+        (None, None) => Cell::DiagonalEllipsis,
+    }).collect()).collect()
+}
+
+impl<T: Display> ToCells for FiniteMatrix<T> {
+    fn to_cells(&self, format: &MatrixFormat, precision: Option<usize>) -> Vec<Vec<Cell>> {
+        let rows = visible(self.height(), format.max_rows);
+        let columns = visible(self.width(), format.max_columns);
+        grid(&rows, &columns, |row, column| format_value(&self[(row, column)], precision))
+    }
+}
+
+impl<T: Semiring + Clone + Display> ToCells for Matrix<T> {
+    fn to_cells(&self, format: &MatrixFormat, precision: Option<usize>) -> Vec<Vec<Cell>> {
+        match self {
+            InfiniteDiagonal(_) => {
+                let indexes = (0..format.diagonal_size).map(Some).chain(once(None)).collect::<Vec<_>>();
+                grid(&indexes, &indexes, |row, column| format_value(&self.get((row, column)), precision))
+            }
+            Finite(matrix) => matrix.to_cells(format, precision),
+        }
+    }
+}
+
+impl MatrixFormat {
+    pub fn new() -> MatrixFormat { MatrixFormat::default() }
+
+    pub fn alignment(self, alignment: Alignment) -> MatrixFormat { MatrixFormat { alignment, ..self } }
+
+    pub fn precision(self, precision: usize) -> MatrixFormat { MatrixFormat { precision: Some(precision), ..self } }
+
+    pub fn brackets(self, brackets: Brackets) -> MatrixFormat { MatrixFormat { brackets, ..self } }
+
+    pub fn max_rows(self, max_rows: usize) -> MatrixFormat { MatrixFormat { max_rows: Some(max_rows), ..self } }
+
+    pub fn max_columns(self, max_columns: usize) -> MatrixFormat { MatrixFormat { max_columns: Some(max_columns), ..self } }
+
+    pub fn diagonal_size(self, diagonal_size: usize) -> MatrixFormat { MatrixFormat { diagonal_size, ..self } }
+
+    fn aligned_rows(&self, cells: Vec<Vec<Cell>>, ellipses: &Ellipses) -> Vec<Vec<String>> {
+        let texts = cells.into_iter().map(|row| row.into_iter().map(|cell| match cell {
+            Cell::Value(text) => text,
+            Cell::HorizontalEllipsis => ellipses.horizontal.to_owned(),
+            Cell::VerticalEllipsis => ellipses.vertical.to_owned(),
+            Cell::DiagonalEllipsis => ellipses.diagonal.to_owned(),
+        }).collect::<Vec<_>>()).collect::<Vec<_>>();
+        let width = texts.first().map_or(0, Vec::len);
+        let column_widths = (0..width)
+            .map(|column| texts.iter().map(|row| row[column].chars().count()).max().unwrap_or(0))
+            .collect::<Vec<_>>();
+        texts.into_iter().map(|row| row.into_iter().zip(&column_widths).map(|(text, &width)| {
+            match self.alignment {
+                Alignment::Left => format!("{text:<width$}"),
+                Alignment::Right => format!("{text:>width$}"),
+                Alignment::Center => format!("{text:^width$}"),
+            }
+        }).collect()).collect()
+    }
+
+    fn write_plain(&self, f: &mut Formatter<'_>, cells: Vec<Vec<Cell>>) -> std::fmt::Result {
+        let (left, right) = match self.brackets {
+            Brackets::None => ("", ""),
+            Brackets::Square => ("[", "]"),
+            Brackets::Round => ("(", ")"),
+            Brackets::Vertical => ("|", "|"),
+        };
+        let rows = self.aligned_rows(cells, &PLAIN);
+        if rows.is_empty() && self.brackets != Brackets::None {
+            writeln!(f, "{left}{right}")?;
+        }
+        for row in rows {
+            writeln!(f, "{left}{}{right}", row.join(" "))?;
+        }
+        Ok(())
+    }
+
+    pub fn display<'a, M: ToCells>(&'a self, matrix: &'a M) -> FormattedMatrix<'a, M> {
+        FormattedMatrix { format: self, matrix }
+    }
+
+    pub fn render<M: ToCells>(&self, matrix: &M) -> String {
+        self.display(matrix).to_string()
+    }
+
+    // the bracket style chooses between `matrix`, `bmatrix`, `pmatrix` and `vmatrix` environments
+    pub fn latex<M: ToCells>(&self, matrix: &M) -> String {
+        let environment = match self.brackets {
+            Brackets::None => "matrix",
+            Brackets::Square => "bmatrix",
+            Brackets::Round => "pmatrix",
+            Brackets::Vertical => "vmatrix",
+        };
+        let rows = self.aligned_rows(matrix.to_cells(self, self.precision), &LATEX)
+            .into_iter()
+            .map(|row| row.join(" & "))
+            .collect::<Vec<_>>();
+        let mut result = format!("\\begin{{{environment}}}\n");
+        if !rows.is_empty() {
+            result += &rows.join(" \\\\\n");
+            result += "\n";
+        }
+        result + &format!("\\end{{{environment}}}")
+    }
+
+    pub fn unicode<M: ToCells>(&self, matrix: &M) -> String {
+        let rows = self.aligned_rows(matrix.to_cells(self, self.precision), &UNICODE)
+            .into_iter()
+            .map(|row| row.join(" "))
+            .collect::<Vec<_>>();
+        let inner_width = rows.first().map_or(0, |row| row.chars().count() + 2);
+        let padding = " ".repeat(inner_width);
+        let mut result = format!("┌{padding}┐\n");
+        for row in rows {
+            result += &format!("│ {row} │\n");
+        }
+        result + &format!("└{padding}┘\n")
+    }
+}
+
+pub struct FormattedMatrix<'a, M> {
+    format: &'a MatrixFormat,
+    matrix: &'a M,
+}
+
+// `{:.3}` is passed to the elements unless the format sets its own precision
+impl<M: ToCells> Display for FormattedMatrix<'_, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precision = self.format.precision.or(f.precision());
+        self.format.write_plain(f, self.matrix.to_cells(self.format, precision))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+    use crate::matrix::FiniteMatrix;
+    use crate::matrix::Matrix::InfiniteDiagonal;
+    use crate::matrix::finite::Shape;
+    use crate::matrix::format::{Alignment, Brackets, MatrixFormat};
+
+    #[test]
+    fn aligned() {
+        let matrix = matrix![1, -20; 300, 4];
+        assert_eq!(MatrixFormat::new().render(&matrix), "[  1 -20]\n[300   4]\n");
+        assert_eq!(
+            MatrixFormat::new().alignment(Alignment::Left).brackets(Brackets::None).render(&matrix),
+            "1   -20\n300 4  \n"
+        );
+        assert_eq!(MatrixFormat::new().render(&FiniteMatrix::<i32>::with_default(Shape::empty())), "[]\n");
+    }
+
+    #[test]
+    fn precision() {
+        let matrix = matrix![1.0, 0.5; 0.25, 2.0];
+        assert_eq!(MatrixFormat::new().precision(2).render(&matrix), "[1.00 0.50]\n[0.25 2.00]\n");
+        assert_eq!(format!("{:.1}", MatrixFormat::new().display(&matrix)), "[1.0 0.5]\n[0.2 2.0]\n");
+        assert_eq!(format!("{:.1}", matrix), "1.0 0.5\n0.2 2.0\n");
+    }
+
+    #[test]
+    fn truncated() {
+        let matrix = FiniteMatrix::from_iter(Shape { height: 5, width: 5 }, 0..25);
+        assert_eq!(
+            MatrixFormat::new().max_rows(2).max_columns(3).render(&matrix),
+            "[  0   1 ...   4]\n[... ... ... ...]\n[ 20  21 ...  24]\n"
+        );
+    }
+
+    #[test]
+    fn latex() {
+        let matrix = matrix![1, 2; 3, 4];
+        assert_eq!(MatrixFormat::new().latex(&matrix), "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{bmatrix}");
+        assert_eq!(
+            MatrixFormat::new().diagonal_size(2).brackets(Brackets::Round).latex(&InfiniteDiagonal(7)),
+            "\\begin{pmatrix}\n     7 &      0 & \\cdots \\\\\n     0 &      7 & \\cdots \\\\\n\\vdots & \\vdots & \\ddots\n\\end{pmatrix}"
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(MatrixFormat::new().unicode(&matrix![1, 20; 3, 4]), "┌      ┐\n│ 1 20 │\n│ 3  4 │\n└      ┘\n");
+        assert_eq!(
+            MatrixFormat::new().diagonal_size(2).unicode(&InfiniteDiagonal(1)),
+            "┌       ┐\n│ 1 0 ⋯ │\n│ 0 1 ⋯ │\n│ ⋮ ⋮ ⋱ │\n└       ┘\n"
+        );
+    }
+}
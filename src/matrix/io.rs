@@ -0,0 +1,342 @@
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use crate::matrix::FiniteMatrix;
+use crate::matrix::finite::Shape;
+use crate::structures::ring_like::{Semiring, zero};
+
+#[derive(Debug)]
+pub enum ReadMatrixError {
+    Io(std::io::Error),
+    Format { line: usize, message: String },
+}
+
+impl Display for ReadMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadMatrixError::Io(error) => write!(f, "{error}"),
+            ReadMatrixError::Format { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadMatrixError {}
+
+impl From<std::io::Error> for ReadMatrixError {
+    fn from(error: std::io::Error) -> Self { ReadMatrixError::Io(error) }
+}
+
+fn format_error<R>(line: usize, message: impl Into<String>) -> Result<R, ReadMatrixError> {
+    Err(ReadMatrixError::Format { line, message: message.into() })
+}
+
+fn parse_token<T: FromStr>(line: usize, token: &str) -> Result<T, ReadMatrixError> {
+    token.parse().or_else(|_| format_error(line, format!("cannot parse `{token}`")))
+}
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum MarketField {
+    Real,
+    Integer,
+}
+
+// sparse matrix as a list of `((row, column), value)` entries, as in the coordinate format
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct CoordinateMatrix<T> {
+    pub shape: Shape,
+    pub entries: Vec<((usize, usize), T)>,
+}
+
+impl<T: Semiring + Clone> CoordinateMatrix<T> {
+    pub fn into_dense(self) -> FiniteMatrix<T> {
+        let mut matrix = FiniteMatrix::from_generator(self.shape, zero);
+        for (index, value) in self.entries {
+            matrix[index] = value;
+        }
+        matrix
+    }
+}
+
+impl<T: Semiring + PartialEq> CoordinateMatrix<T> {
+    pub fn from_dense(matrix: FiniteMatrix<T>) -> CoordinateMatrix<T> {
+        let shape = matrix.shape();
+        let entries = matrix.into_iter_with_indexes().filter(|(_, value)| *value != zero()).collect();
+        CoordinateMatrix { shape, entries }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub enum MatrixMarket<T> {
+    Array(FiniteMatrix<T>),
+    Coordinate(CoordinateMatrix<T>),
+}
+
+impl<T: Semiring + Clone> MatrixMarket<T> {
+    pub fn into_dense(self) -> FiniteMatrix<T> {
+        match self {
+            MatrixMarket::Array(matrix) => matrix,
+            MatrixMarket::Coordinate(matrix) => matrix.into_dense(),
+        }
+    }
+}
+
+
+struct Lines<R> {
+    reader: R,
+    number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_line(&mut self) -> Result<Option<String>, ReadMatrixError> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        self.number += 1;
+        Ok(Some(line))
+    }
+
+    // skips comments and blank lines
+    fn next_data_line(&mut self) -> Result<Option<String>, ReadMatrixError> {
+        while let Some(line) = self.next_line()? {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some(trimmed.to_owned()));
+            }
+        }
+        Ok(None)
+    }
+
+    fn expect_data_line(&mut self) -> Result<String, ReadMatrixError> {
+        match self.next_data_line()? {
+            Some(line) => Ok(line),
+            None => format_error(self.number, "unexpected end of input"),
+        }
+    }
+}
+
+fn parse_tokens<T: FromStr>(line_number: usize, line: &str, count: usize) -> Result<Vec<T>, ReadMatrixError> {
+    let tokens = line.split_whitespace().collect::<Vec<_>>();
+    if tokens.len() != count {
+        return format_error(line_number, format!("expected {count} values, found {}", tokens.len()));
+    }
+    tokens.into_iter().map(|token| parse_token(line_number, token)).collect()
+}
+
+pub fn read_matrix_market<T: FromStr + Clone>(reader: impl BufRead) -> Result<MatrixMarket<T>, ReadMatrixError> {
+    let mut lines = Lines { reader, number: 0 };
+    let header = match lines.next_line()? {
+        Some(header) => header,
+        None => return format_error(0, "empty input"),
+    };
+    let header = header.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>();
+    if header.len() != 5 || header[0] != "%%matrixmarket" || header[1] != "matrix" {
+        return format_error(1, "expected `%%MatrixMarket matrix <format> <field> <symmetry>` header");
+    }
+    if header[3] != "real" && header[3] != "integer" {
+        return format_error(1, format!("unsupported field `{}`", header[3]));
+    }
+    let symmetric = match header[4].as_str() {
+        "general" => false,
+        "symmetric" => true,
+        symmetry => return format_error(1, format!("unsupported symmetry `{symmetry}`")),
+    };
+    match header[2].as_str() {
+        "array" => read_array(&mut lines, symmetric).map(MatrixMarket::Array),
+        "coordinate" => read_coordinate(&mut lines, symmetric).map(MatrixMarket::Coordinate),
+        format => format_error(1, format!("unknown format `{format}`")),
+    }
+}
+
+fn read_array<T: FromStr + Clone>(lines: &mut Lines<impl BufRead>, symmetric: bool) -> Result<FiniteMatrix<T>, ReadMatrixError> {
+    let size_line = lines.expect_data_line()?;
+    let size = parse_tokens::<usize>(lines.number, &size_line, 2)?;
+    let shape = Shape { height: size[0], width: size[1] };
+    if symmetric && shape.height != shape.width {
+        return format_error(lines.number, "symmetric matrix must be square");
+    }
+    // entries are listed column by column; symmetric matrices list only the lower triangle
+    let mut columns = vec![Vec::with_capacity(shape.height); shape.width];
+    for (column, values) in columns.iter_mut().enumerate() {
+        let first_row = if symmetric { column } else { 0 };
+        for _ in first_row..shape.height {
+            let line = lines.expect_data_line()?;
+            values.push(parse_tokens::<T>(lines.number, &line, 1)?.remove(0));
+        }
+    }
+    Ok(FiniteMatrix::from_indexed_generator(shape, |row, column| {
+        if symmetric && row < column {
+            columns[row][column - row].clone()
+        } else if symmetric {
+            columns[column][row - column].clone()
+        } else {
+            columns[column][row].clone()
+        }
+    }))
+}
+
+fn read_coordinate<T: FromStr + Clone>(lines: &mut Lines<impl BufRead>, symmetric: bool) -> Result<CoordinateMatrix<T>, ReadMatrixError> {
+    let size_line = lines.expect_data_line()?;
+    let size = parse_tokens::<usize>(lines.number, &size_line, 3)?;
+    let shape = Shape { height: size[0], width: size[1] };
+    let mut entries = Vec::with_capacity(size[2]);
+    for _ in 0..size[2] {
+        let line = lines.expect_data_line()?;
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() != 3 {
+            return format_error(lines.number, "expected `<row> <column> <value>`");
+        }
+        let row = parse_token::<usize>(lines.number, tokens[0])?;
+        let column = parse_token::<usize>(lines.number, tokens[1])?;
+        if row == 0 || row > shape.height || column == 0 || column > shape.width {
+            return format_error(lines.number, format!("index ({row}, {column}) is out of bounds"));
+        }
+        let value = parse_token::<T>(lines.number, tokens[2])?;
+        if symmetric && row != column {
+            entries.push(((column - 1, row - 1), value.clone()));
+        }
+        entries.push(((row - 1, column - 1), value));
+    }
+    Ok(CoordinateMatrix { shape, entries })
+}
+
+fn field_name(field: MarketField) -> &'static str {
+    match field {
+        MarketField::Real => "real",
+        MarketField::Integer => "integer",
+    }
+}
+
+pub fn write_matrix_market_array<T: Display>(
+    mut writer: impl Write, matrix: &FiniteMatrix<T>, field: MarketField,
+) -> std::io::Result<()> {
+    writeln!(writer, "%%MatrixMarket matrix array {} general", field_name(field))?;
+    writeln!(writer, "{} {}", matrix.height(), matrix.width())?;
+    for column in 0..matrix.width() {
+        for row in 0..matrix.height() {
+            writeln!(writer, "{}", matrix[(row, column)])?;
+        }
+    }
+    Ok(())
+}
+
+pub fn write_matrix_market_coordinate<T: Display>(
+    mut writer: impl Write, matrix: &CoordinateMatrix<T>, field: MarketField,
+) -> std::io::Result<()> {
+    writeln!(writer, "%%MatrixMarket matrix coordinate {} general", field_name(field))?;
+    writeln!(writer, "{} {} {}", matrix.shape.height, matrix.shape.width, matrix.entries.len())?;
+    for ((row, column), value) in &matrix.entries {
+        writeln!(writer, "{} {} {}", row + 1, column + 1, value)?;
+    }
+    Ok(())
+}
+
+
+pub fn read_csv<T: FromStr>(reader: impl BufRead) -> Result<FiniteMatrix<T>, ReadMatrixError> {
+    let mut lines = Lines { reader, number: 0 };
+    let mut storage = Vec::new();
+    let mut width = None;
+    while let Some(line) = lines.next_line()? {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let row = line.split(',')
+            .map(|token| parse_token::<T>(lines.number, token.trim()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match width {
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                return format_error(lines.number, format!("expected {width} columns, found {}", row.len()));
+            }
+            Some(_) => {}
+        }
+        storage.extend(row);
+    }
+    let width = width.unwrap_or(0);
+    let height = storage.len().checked_div(width).unwrap_or(0);
+    Ok(FiniteMatrix::from_iter(Shape { height, width }, storage.into_iter()))
+}
+
+pub fn write_csv<T: Display>(mut writer: impl Write, matrix: &FiniteMatrix<T>) -> std::io::Result<()> {
+    for row in 0..matrix.height() {
+        for column in 0..matrix.width() {
+            if column != 0 {
+                write!(writer, ",")?;
+            }
+            write!(writer, "{}", matrix[(row, column)])?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::FiniteMatrix;
+    use crate::matrix::finite::Shape;
+    use crate::matrix::io::{CoordinateMatrix, MarketField, MatrixMarket, read_csv, read_matrix_market,
+                            write_csv, write_matrix_market_array, write_matrix_market_coordinate};
+
+    #[test]
+    fn matrix_market_array() {
+        let matrix = FiniteMatrix::from_iter(Shape { height: 2, width: 3 }, 1..7);
+        let mut output = Vec::new();
+        write_matrix_market_array(&mut output, &matrix, MarketField::Integer).unwrap();
+        assert_eq!(
+            String::from_utf8(output.clone()).unwrap(),
+            "%%MatrixMarket matrix array integer general\n2 3\n1\n4\n2\n5\n3\n6\n"
+        );
+        assert_eq!(read_matrix_market::<i32>(output.as_slice()).unwrap(), MatrixMarket::Array(matrix));
+    }
+
+    #[test]
+    fn matrix_market_symmetric_array() {
+        let input = "%%MatrixMarket matrix array real symmetric\n% comment\n2 2\n1.5\n2\n3\n";
+        let expected = FiniteMatrix::from_iter(Shape { height: 2, width: 2 }, vec![1.5, 2.0, 2.0, 3.0].into_iter());
+        assert_eq!(read_matrix_market::<f64>(input.as_bytes()).unwrap().into_dense(), expected);
+    }
+
+    #[test]
+    fn matrix_market_coordinate() {
+        let matrix = FiniteMatrix::from_iter(Shape { height: 2, width: 3 }, vec![0, 7, 0, 0, 0, -1].into_iter());
+        let sparse = CoordinateMatrix::from_dense(matrix.clone());
+        assert_eq!(sparse.entries, vec![((0, 1), 7), ((1, 2), -1)]);
+        let mut output = Vec::new();
+        write_matrix_market_coordinate(&mut output, &sparse, MarketField::Integer).unwrap();
+        assert_eq!(
+            String::from_utf8(output.clone()).unwrap(),
+            "%%MatrixMarket matrix coordinate integer general\n2 3 2\n1 2 7\n2 3 -1\n"
+        );
+        let read = read_matrix_market::<i32>(output.as_slice()).unwrap();
+        assert_eq!(read, MatrixMarket::Coordinate(sparse));
+        assert_eq!(read.into_dense(), matrix);
+    }
+
+    #[test]
+    fn matrix_market_errors() {
+        assert!(read_matrix_market::<i32>("".as_bytes()).is_err());
+        assert!(read_matrix_market::<i32>("%%MatrixMarket matrix array complex general\n".as_bytes()).is_err());
+        let out_of_bounds = "%%MatrixMarket matrix coordinate integer general\n1 1 1\n2 1 5\n";
+        assert!(read_matrix_market::<i32>(out_of_bounds.as_bytes()).is_err());
+        let truncated = "%%MatrixMarket matrix array integer general\n2 2\n1\n2\n3\n";
+        assert_eq!(
+            read_matrix_market::<i32>(truncated.as_bytes()).unwrap_err().to_string(),
+            "line 5: unexpected end of input"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let matrix = FiniteMatrix::from_iter(Shape { height: 2, width: 2 }, vec![1.5, -2.0, 0.0, 4.25].into_iter());
+        let mut output = Vec::new();
+        write_csv(&mut output, &matrix).unwrap();
+        assert_eq!(String::from_utf8(output.clone()).unwrap(), "1.5,-2\n0,4.25\n");
+        assert_eq!(read_csv::<f64>(output.as_slice()).unwrap(), matrix);
+        assert_eq!(read_csv::<i32>(" 1, 2 \n\n3,4\n".as_bytes()).unwrap(), FiniteMatrix::from_iter(Shape { height: 2, width: 2 }, 1..5));
+        assert!(read_csv::<i32>("1,2\n3\n".as_bytes()).is_err());
+        assert!(read_csv::<i32>("1,x\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::primality::is_prime;
+
+// residue modulo `M`, the ring `ℤ/Mℤ` whose units `try_inverse` detects, `Zp` is the field for prime moduli
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Default)]
+pub struct Zmod<const M: u64>(u64);
+
+impl<const M: u64> Zmod<M> {
+    pub const IS_PRIME: bool = is_prime(M);
+
+    pub fn new(value: u64) -> Zmod<M> { Zmod(value % M) }
+
+    pub fn from_signed(value: i64) -> Zmod<M> { Zmod((value as i128).rem_euclid(M as i128) as u64) }
+
+    pub fn value(self) -> u64 { self.0 }
+
+    pub fn modulus() -> u64 { M }
+
+    // `None` for non-units
+    pub fn try_inverse(self) -> Option<Zmod<M>> {
+        let (mut r0, mut r1) = (M as i128, self.0 as i128);
+        let (mut s0, mut s1) = (0i128, 1i128);
+        while r1 != 0 {
+            let quotient = r0 / r1;
+            (r0, r1) = (r1, r0 - quotient * r1);
+            (s0, s1) = (s1, s0 - quotient * s1);
+        }
+        if r0 == 1 { Some(Zmod(s0.rem_euclid(M as i128) as u64)) } else { None }
+    }
+}
+
+impl<const M: u64> From<u64> for Zmod<M> {
+    fn from(value: u64) -> Self { Zmod::new(value) }
+}
+
+impl<const M: u64> Display for Zmod<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+impl<const M: u64> Add for Zmod<M> {
+    type Output = Zmod<M>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Zmod(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Sub for Zmod<M> {
+    type Output = Zmod<M>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const M: u64> Neg for Zmod<M> {
+    type Output = Zmod<M>;
+
+    fn neg(self) -> Self::Output {
+        Zmod((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> Mul for Zmod<M> {
+    type Output = Zmod<M>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Zmod((self.0 as u128 * rhs.0 as u128 % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> Div for Zmod<M> {
+    type Output = Zmod<M>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let inverse = rhs.try_inverse().expect("Division by a non-invertible residue");
+        Mul::mul(self, inverse)
+    }
+}
+
+
+// residue modulo a prime `P`, the field `𝔽_P`, composite `P` are rejected at compile time by the constructors
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct Zp<const P: u64>(Zmod<P>);
+
+impl<const P: u64> Zp<P> {
+    pub fn new(value: u64) -> Zp<P> { Zp::from_residue(Zmod::new(value)) }
+
+    pub fn from_signed(value: i64) -> Zp<P> { Zp::from_residue(Zmod::from_signed(value)) }
+
+    pub fn from_residue(residue: Zmod<P>) -> Zp<P> {
+        const { assert!(Zmod::<P>::IS_PRIME, "Zp<P> is a field only for prime P") };
+        Zp(residue)
+    }
+
+    pub fn residue(self) -> Zmod<P> { self.0 }
+
+    pub fn value(self) -> u64 { self.0.value() }
+
+    pub fn modulus() -> u64 { P }
+
+    // `None` only for zero
+    pub fn try_inverse(self) -> Option<Zp<P>> { self.0.try_inverse().map(Zp) }
+}
+
+impl<const P: u64> From<u64> for Zp<P> {
+    fn from(value: u64) -> Self { Zp::new(value) }
+}
+
+impl<const P: u64> Display for Zp<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+impl<const P: u64> Add for Zp<P> {
+    type Output = Zp<P>;
+
+    fn add(self, rhs: Self) -> Self::Output { Zp(self.0 + rhs.0) }
+}
+
+impl<const P: u64> Sub for Zp<P> {
+    type Output = Zp<P>;
+
+    fn sub(self, rhs: Self) -> Self::Output { Zp(self.0 - rhs.0) }
+}
+
+impl<const P: u64> Neg for Zp<P> {
+    type Output = Zp<P>;
+
+    fn neg(self) -> Self::Output { Zp(-self.0) }
+}
+
+impl<const P: u64> Mul for Zp<P> {
+    type Output = Zp<P>;
+
+    fn mul(self, rhs: Self) -> Self::Output { Zp(self.0 * rhs.0) }
+}
+
+impl<const P: u64> Div for Zp<P> {
+    type Output = Zp<P>;
+
+    fn div(self, rhs: Self) -> Self::Output { Zp(self.0 / rhs.0) }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use crate::polynomial::Polynomial;
+use crate::structures::basics::{Invertible, Magma};
+use crate::structures::ring_like::{multi, one, plus, Semiring, SemiringWithOne, zero};
+
+// finite formal sum `Σ rᵢ·mᵢ` of elements of the monoid `(T, G)` with coefficients in `R`,
+// sorted by element without zero coefficients; the product extends `G::operation` bilinearly
+pub struct MonoidAlgebra<G, T, R> {
+    terms: Vec<(T, R)>,
+    structure: PhantomData<G>,
+}
+
+pub type GroupAlgebra<G, T, R> = MonoidAlgebra<G, T, R>;
+
+impl<G, T: Ord, R: Semiring + PartialEq> MonoidAlgebra<G, T, R> {
+    // equal elements are merged
+    pub fn new(terms: impl IntoIterator<Item=(T, R)>) -> MonoidAlgebra<G, T, R> {
+        let mut merged = BTreeMap::new();
+        for (element, coefficient) in terms {
+            let sum = match merged.remove(&element) {
+                Some(previous) => plus(previous, coefficient),
+                None => coefficient,
+            };
+            merged.insert(element, sum);
+        }
+        MonoidAlgebra {
+            terms: merged.into_iter().filter(|(_, coefficient)| *coefficient != zero()).collect(),
+            structure: PhantomData,
+        }
+    }
+
+    pub fn term(element: T, coefficient: R) -> MonoidAlgebra<G, T, R> { MonoidAlgebra::new([(element, coefficient)]) }
+}
+
+impl<G, T: Ord, R: SemiringWithOne + PartialEq> MonoidAlgebra<G, T, R> {
+    pub fn basis(element: T) -> MonoidAlgebra<G, T, R> { MonoidAlgebra::term(element, one()) }
+}
+
+impl<G, T, R> MonoidAlgebra<G, T, R> {
+    pub fn terms(&self) -> &[(T, R)] { &self.terms }
+
+    pub fn into_terms(self) -> Vec<(T, R)> { self.terms }
+
+    pub fn is_zero(&self) -> bool { self.terms.is_empty() }
+}
+
+impl<G, T: Ord, R: Semiring + Clone> MonoidAlgebra<G, T, R> {
+    pub fn coefficient(&self, element: &T) -> R {
+        match self.terms.binary_search_by(|(e, _)| e.cmp(element)) {
+            Ok(index) => self.terms[index].1.clone(),
+            Err(_) => zero(),
+        }
+    }
+}
+
+impl<G: Magma<T>, T: Ord + Clone, R: Semiring + Clone + PartialEq> MonoidAlgebra<G, T, R> {
+    pub fn multiply(&self, other: &MonoidAlgebra<G, T, R>) -> MonoidAlgebra<G, T, R> {
+        MonoidAlgebra::new(self.terms.iter().flat_map(|(e1, c1)| other.terms.iter().map(move |(e2, c2)| {
+            (G::operation(e1.clone(), e2.clone()), multi(c1.clone(), c2.clone()))
+        })))
+    }
+}
+
+impl<G: Invertible<T>, T: Ord, R: Semiring + PartialEq> MonoidAlgebra<G, T, R> {
+    // `Σ rᵢ·gᵢ ↦ Σ rᵢ·gᵢ⁻¹`
+    pub fn involution(self) -> MonoidAlgebra<G, T, R> {
+        MonoidAlgebra::new(self.terms.into_iter().map(|(element, coefficient)| (G::inverse(element), coefficient)))
+    }
+}
+
+// the polynomial ring is the monoid algebra of `(u64, +)`
+impl<R: Semiring + PartialEq> From<Polynomial<R>> for MonoidAlgebra<<u64 as Semiring>::Sum, u64, R> {
+    fn from(polynomial: Polynomial<R>) -> Self {
+        MonoidAlgebra::new(polynomial.into_coefficients().into_iter().enumerate().map(|(degree, c)| (degree as u64, c)))
+    }
+}
+
+impl<R: Semiring + PartialEq> From<MonoidAlgebra<<u64 as Semiring>::Sum, u64, R>> for Polynomial<R> {
+    fn from(algebra: MonoidAlgebra<<u64 as Semiring>::Sum, u64, R>) -> Self {
+        let mut coefficients = Vec::new();
+        for (degree, c) in algebra.into_terms() {
+            coefficients.resize_with(degree as usize, zero);
+            coefficients.push(c);
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+// `PhantomData<G>` would otherwise require `G` to implement these traits
+
+impl<G, T: Clone, R: Clone> Clone for MonoidAlgebra<G, T, R> {
+    fn clone(&self) -> Self { MonoidAlgebra { terms: self.terms.clone(), structure: PhantomData } }
+}
+
+impl<G, T: PartialEq, R: PartialEq> PartialEq for MonoidAlgebra<G, T, R> {
+    fn eq(&self, other: &Self) -> bool { self.terms == other.terms }
+}
+
+impl<G, T: Eq, R: Eq> Eq for MonoidAlgebra<G, T, R> {}
+
+impl<G, T: Hash, R: Hash> Hash for MonoidAlgebra<G, T, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.terms.hash(state) }
+}
+
+impl<G, T: Debug, R: Debug> Debug for MonoidAlgebra<G, T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MonoidAlgebra").field("terms", &self.terms).finish()
+    }
+}
+
+impl<G, T: Display, R: Display> Display for MonoidAlgebra<G, T, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (index, (element, coefficient)) in self.terms.iter().enumerate() {
+            if index != 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{coefficient}·[{element}]")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Mul;
+use std::str::FromStr;
+use num::{BigUint, Integer, One};
+use crate::matrix::{FiniteMatrix, Matrix};
+use crate::matrix::finite::Shape;
+use crate::structures::ring_like::{one, SemiringWithOne, zero};
+
+pub mod schreier_sims;
+
+// bijection of `0..n` stored by images without trailing fixed points, so it is the same for every `n`
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash, Default)]
+pub struct Permutation {
+    images: Vec<usize>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParsePermutationError {
+    Syntax(String),
+    Point(String),
+    RepeatedPoint(usize),
+}
+
+impl Display for ParsePermutationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePermutationError::Syntax(message) => write!(f, "Invalid cycle notation: {message}"),
+            ParsePermutationError::Point(point) => write!(f, "Invalid point: {point}"),
+            ParsePermutationError::RepeatedPoint(point) => write!(f, "Point {point} is repeated in a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePermutationError {}
+
+impl Permutation {
+    pub fn identity() -> Permutation { Permutation::default() }
+
+    // `None` unless `images` is a rearrangement of `0..images.len()`
+    pub fn from_images(images: Vec<usize>) -> Option<Permutation> {
+        let mut seen = vec![false; images.len()];
+        for &image in &images {
+            if image >= images.len() || std::mem::replace(&mut seen[image], true) {
+                return None;
+            }
+        }
+        Some(Permutation::trimmed(images))
+    }
+
+    fn trimmed(mut images: Vec<usize>) -> Permutation {
+        while images.last().is_some_and(|&image| image + 1 == images.len()) {
+            images.pop();
+        }
+        Permutation { images }
+    }
+
+    // `points[0] → points[1] → … → points[0]`, panics on repeated points
+    pub fn cycle(points: &[usize]) -> Permutation {
+        let size = points.iter().map(|&point| point + 1).max().unwrap_or(0);
+        let mut images = (0..size).collect::<Vec<_>>();
+        for (index, &point) in points.iter().enumerate() {
+            images[point] = points[(index + 1) % points.len()];
+        }
+        Permutation::from_images(images).expect("Cycle points must be distinct")
+    }
+
+    pub fn transposition(a: usize, b: usize) -> Permutation { Permutation::cycle(&[a, b]) }
+
+    pub fn apply(&self, point: usize) -> usize {
+        self.images.get(point).copied().unwrap_or(point)
+    }
+
+    // images of `0..degree()`, every larger point is fixed
+    pub fn images(&self) -> &[usize] { &self.images }
+
+    pub fn degree(&self) -> usize { self.images.len() }
+
+    pub fn is_identity(&self) -> bool { self.images.is_empty() }
+
+    // `self ∘ other`, `other` is applied first
+    pub fn compose(&self, other: &Permutation) -> Permutation {
+        let size = self.degree().max(other.degree());
+        Permutation::trimmed((0..size).map(|point| self.apply(other.apply(point))).collect())
+    }
+
+    pub fn inverse(&self) -> Permutation {
+        let mut images = vec![0; self.degree()];
+        for (point, &image) in self.images.iter().enumerate() {
+            images[image] = point;
+        }
+        Permutation { images }
+    }
+
+    // non-trivial cycles, each starting with its least point, ordered by it
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.degree()];
+        let mut cycles = Vec::new();
+        for start in 0..self.degree() {
+            if visited[start] || self.images[start] == start {
+                continue;
+            }
+            let mut cycle = vec![start];
+            visited[start] = true;
+            let mut point = self.images[start];
+            while point != start {
+                visited[point] = true;
+                cycle.push(point);
+                point = self.images[point];
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.cycles().iter().map(|cycle| cycle.len() - 1).sum::<usize>().is_multiple_of(2)
+    }
+
+    // `1` for even permutations, `-1` for odd ones
+    pub fn sign(&self) -> i8 { if self.is_even() { 1 } else { -1 } }
+
+    pub fn order(&self) -> BigUint {
+        self.cycles().iter().fold(BigUint::one(), |order, cycle| order.lcm(&BigUint::from(cycle.len())))
+    }
+
+    // `size × size` matrix sending the basis vector `eᵢ` to `e_σ(i)`, panics when `size < degree()`
+    pub fn matrix<T: SemiringWithOne>(&self, size: usize) -> Matrix<T> {
+        assert!(self.degree() <= size, "Permutation moves points outside of the matrix");
+        let shape = Shape { height: size, width: size };
+        Matrix::Finite(FiniteMatrix::from_indexed_generator(shape, |row, column| {
+            if self.apply(column) == row { one() } else { zero() }
+        }))
+    }
+}
+
+impl Display for Permutation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let cycles = self.cycles();
+        if cycles.is_empty() {
+            return write!(f, "()");
+        }
+        for cycle in cycles {
+            let points = cycle.iter().map(ToString::to_string).collect::<Vec<_>>();
+            write!(f, "({})", points.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+// product of cycles such as `(0 1 2)(3 4)`, the rightmost one is applied first; points may be separated by commas
+impl FromStr for Permutation {
+    type Err = ParsePermutationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s.trim();
+        let mut result = Permutation::identity();
+        while !rest.is_empty() {
+            let body = rest.strip_prefix('(')
+                .ok_or_else(|| ParsePermutationError::Syntax(format!("expected '(' at '{rest}'")))?;
+            let end = body.find(')')
+                .ok_or_else(|| ParsePermutationError::Syntax("unclosed cycle".to_owned()))?;
+            let points = body[..end].split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|point| !point.is_empty())
+                .map(|point| point.parse().map_err(|_| ParsePermutationError::Point(point.to_owned())))
+                .collect::<Result<Vec<usize>, _>>()?;
+            let mut sorted = points.clone();
+            sorted.sort_unstable();
+            if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+                return Err(ParsePermutationError::RepeatedPoint(pair[0]));
+            }
+            result = result.compose(&Permutation::cycle(&points));
+            rest = body[end + 1..].trim_start();
+        }
+        Ok(result)
+    }
+}
+
+impl Mul for Permutation {
+    type Output = Permutation;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use crate::matrix;
+    use crate::permutation::{ParsePermutationError, Permutation};
+
+    #[test]
+    fn cycle_notation() {
+        let permutation = "(0 1 2)(3 4)".parse::<Permutation>().unwrap();
+        assert_eq!(permutation.images(), [1, 2, 0, 4, 3]);
+        assert_eq!(permutation.to_string(), "(0 1 2)(3 4)");
+        assert_eq!("(2, 0, 1) (5)".parse::<Permutation>().unwrap().to_string(), "(0 1 2)");
+        assert_eq!("(0 1)(1 2)".parse::<Permutation>().unwrap().to_string(), "(0 1 2)");
+        assert_eq!("".parse::<Permutation>(), Ok(Permutation::identity()));
+        assert_eq!(Permutation::identity().to_string(), "()");
+        assert_eq!("(0 1 0)".parse::<Permutation>(), Err(ParsePermutationError::RepeatedPoint(0)));
+        assert_eq!("(0 x)".parse::<Permutation>(), Err(ParsePermutationError::Point("x".to_owned())));
+        assert!(matches!("(0 1".parse::<Permutation>(), Err(ParsePermutationError::Syntax(_))));
+    }
+
+    #[test]
+    fn properties() {
+        let permutation = Permutation::from_images(vec![1, 2, 0, 4, 3, 5]).unwrap();
+        assert_eq!(permutation.degree(), 5);
+        assert_eq!(permutation.sign(), -1);
+        assert_eq!(permutation.order(), BigUint::from(6u32));
+        assert_eq!(permutation.compose(&permutation.inverse()), Permutation::identity());
+        assert_eq!(Permutation::from_images(vec![0, 0]), None);
+        assert!(Permutation::cycle(&[0, 3, 7]).is_even());
+        assert_eq!(Permutation::transposition(0, 1) * Permutation::transposition(1, 2), Permutation::cycle(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn permutation_matrix() {
+        let permutation = Permutation::cycle(&[0, 1, 2]);
+        assert_eq!(permutation.matrix::<i32>(3), matrix![0, 0, 1; 1, 0, 0; 0, 1, 0]);
+        let product = Permutation::transposition(0, 1) * permutation.clone();
+        assert_eq!(product.matrix::<i32>(4), Permutation::transposition(0, 1).matrix::<i32>(4) * permutation.matrix(4));
+    }
+}
@@ -0,0 +1,195 @@
+use num::BigUint;
+use crate::implementations::permutation::Composition;
+use crate::permutation::Permutation;
+use crate::structures::basics::{Invertible, Magma, WithIdentityElement};
+
+// `transversal[x]` maps the base point to `x` for every `x` in its orbit under `generators`
+#[derive(Clone, Debug)]
+struct Level {
+    base_point: usize,
+    generators: Vec<Permutation>,
+    orbit: Vec<usize>,
+    transversal: Vec<Option<Permutation>>,
+}
+
+impl Level {
+    fn new(base_point: usize, degree: usize) -> Level {
+        let mut transversal = vec![None; degree];
+        transversal[base_point] = Some(Composition::identity());
+        Level { base_point, generators: vec![], orbit: vec![base_point], transversal }
+    }
+
+    fn representative(&self, point: usize) -> Option<&Permutation> {
+        self.transversal.get(point)?.as_ref()
+    }
+}
+
+// stabilizer chain by Schreier–Sims, level `i` holds the orbit of the next base point under the stabilizer of the first `i`
+#[derive(Clone, Debug)]
+pub struct PermutationGroup {
+    degree: usize,
+    levels: Vec<Level>,
+}
+
+impl PermutationGroup {
+    pub fn new(generators: impl IntoIterator<Item=Permutation>) -> PermutationGroup {
+        let generators = generators.into_iter().collect::<Vec<_>>();
+        let degree = generators.iter().map(Permutation::degree).max().unwrap_or(0);
+        let mut group = PermutationGroup { degree, levels: vec![] };
+        for generator in generators {
+            group.sift_and_add(0, generator);
+        }
+        group
+    }
+
+    // strips `element` by the transversals from level `from`, returns the residue and the level it stopped at
+    fn sift(&self, from: usize, mut element: Permutation) -> (Permutation, usize) {
+        for (index, level) in self.levels.iter().enumerate().skip(from) {
+            match level.representative(element.apply(level.base_point)) {
+                Some(representative) => element = Composition::operation(Composition::inverse(representative.clone()), element),
+                None => return (element, index),
+            }
+        }
+        (element, self.levels.len())
+    }
+
+    // the residue fixes the base points up to the level it stopped at, so it joins the generators of every level from `from` to it
+    fn sift_and_add(&mut self, from: usize, element: Permutation) {
+        let (residue, index) = self.sift(from, element);
+        if residue.is_identity() {
+            return;
+        }
+        if index == self.levels.len() {
+            let moved = (0..residue.degree()).find(|&point| residue.apply(point) != point).unwrap();
+            self.levels.push(Level::new(moved, self.degree));
+        }
+        for level in (from..=index).rev() {
+            self.add_generator(level, residue.clone());
+        }
+    }
+
+    // keeps every Schreier generator of the level sifting through the levels below it
+    fn add_generator(&mut self, index: usize, generator: Permutation) {
+        self.levels[index].generators.push(generator.clone());
+        let known = self.levels[index].orbit.len();
+        for position in 0..known {
+            self.extend_orbit(index, self.levels[index].orbit[position], &generator);
+        }
+        let mut position = known;
+        while position < self.levels[index].orbit.len() {
+            let point = self.levels[index].orbit[position];
+            for generator in self.levels[index].generators.clone() {
+                self.extend_orbit(index, point, &generator);
+            }
+            position += 1;
+        }
+    }
+
+    fn extend_orbit(&mut self, index: usize, point: usize, generator: &Permutation) {
+        let level = &mut self.levels[index];
+        let image = generator.apply(point);
+        let product = Composition::operation(generator.clone(), level.representative(point).unwrap().clone());
+        match level.representative(image) {
+            None => {
+                level.transversal[image] = Some(product);
+                level.orbit.push(image);
+            }
+            Some(representative) => {
+                let schreier = Composition::operation(Composition::inverse(representative.clone()), product);
+                self.sift_and_add(index + 1, schreier);
+            }
+        }
+    }
+
+    pub fn order(&self) -> BigUint {
+        self.levels.iter().map(|level| BigUint::from(level.orbit.len())).product()
+    }
+
+    pub fn contains(&self, element: &Permutation) -> bool {
+        self.sift(0, element.clone()).0.is_identity()
+    }
+
+    // uniformly distributed when `random(n)` is uniform on `0..n`
+    pub fn random_element(&self, mut random: impl FnMut(usize) -> usize) -> Permutation {
+        self.levels.iter().rev().fold(Composition::identity(), |element, level| {
+            let point = level.orbit[random(level.orbit.len())];
+            Composition::operation(level.representative(point).unwrap().clone(), element)
+        })
+    }
+
+    // no non-identity element fixes every base point
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|level| level.base_point).collect()
+    }
+
+    // generates the group and, with the base, each stabilizer in the chain
+    pub fn strong_generators(&self) -> Vec<Permutation> {
+        let mut generators: Vec<Permutation> = vec![];
+        for generator in self.levels.iter().flat_map(|level| &level.generators) {
+            if !generators.contains(generator) {
+                generators.push(generator.clone());
+            }
+        }
+        generators
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use crate::permutation::Permutation;
+    use crate::permutation::schreier_sims::PermutationGroup;
+
+    fn group(generators: &[&str]) -> PermutationGroup {
+        PermutationGroup::new(generators.iter().map(|generator| generator.parse::<Permutation>().unwrap()))
+    }
+
+    #[test]
+    fn symmetric_and_alternating() {
+        let symmetric = group(&["(0 1)", "(0 1 2 3 4 5 6)"]);
+        assert_eq!(symmetric.order(), BigUint::from(5040u32));
+        let alternating = group(&["(0 1 2)", "(2 3 4)"]);
+        assert_eq!(alternating.order(), BigUint::from(60u32));
+        assert!(alternating.contains(&"(0 1)(2 3)".parse().unwrap()));
+        assert!(!alternating.contains(&"(0 1)".parse().unwrap()));
+        assert!(!alternating.contains(&"(0 5)".parse().unwrap()));
+        assert!(alternating.contains(&Permutation::identity()));
+        assert_eq!(PermutationGroup::new([]).order(), BigUint::from(1u32));
+    }
+
+    #[test]
+    fn rubiks_cube() {
+        let cube = group(&[
+            "(1,3,8,6)(2,5,7,4)(9,33,25,17)(10,34,26,18)(11,35,27,19)",
+            "(9,11,16,14)(10,13,15,12)(1,17,41,40)(4,20,44,37)(6,22,46,35)",
+            "(17,19,24,22)(18,21,23,20)(6,25,43,16)(7,28,42,13)(8,30,41,11)",
+            "(25,27,32,30)(26,29,31,28)(3,38,43,19)(5,36,45,21)(8,33,48,24)",
+            "(33,35,40,38)(34,37,39,36)(3,9,46,32)(2,12,47,29)(1,14,48,27)",
+            "(41,43,48,46)(42,45,47,44)(14,22,30,38)(15,23,31,39)(16,24,32,40)",
+        ]);
+        assert_eq!(cube.order(), "43252003274489856000".parse().unwrap());
+        // a single corner twist is impossible
+        assert!(!cube.contains(&"(8,24,30)".parse().unwrap()));
+        let mut state = 1u64;
+        let mut random = |n: usize| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize % n
+        };
+        for _ in 0..10 {
+            assert!(cube.contains(&cube.random_element(&mut random)));
+        }
+    }
+
+    #[test]
+    fn base_and_strong_generators() {
+        let group = group(&["(0 1 2 3)", "(0 2)"]);
+        let base = group.base();
+        assert_eq!(group.order(), BigUint::from(8u32));
+        let strong = group.strong_generators();
+        assert!(strong.iter().all(|generator| group.contains(generator)));
+        assert_eq!(PermutationGroup::new(strong.clone()).order(), group.order());
+        let stabilizer = strong.into_iter().filter(|generator| generator.apply(base[0]) == base[0]);
+        assert_eq!(PermutationGroup::new(stabilizer).order(), BigUint::from(2u32));
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use crate::structures::ring_like::{multi, plus, Ring, Semiring, unary_minus, zero};
+
+pub mod convolution;
+pub mod transform;
+
+// coefficients are stored lowest degree first without trailing zeros, so the zero polynomial is empty
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Polynomial<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T: Semiring + PartialEq> Polynomial<T> {
+    pub fn new(mut coefficients: Vec<T>) -> Polynomial<T> {
+        while coefficients.last().is_some_and(|c| *c == zero()) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    pub fn monomial(coefficient: T, degree: usize) -> Polynomial<T> {
+        let mut coefficients = Vec::with_capacity(degree + 1);
+        coefficients.resize_with(degree, zero);
+        coefficients.push(coefficient);
+        Polynomial::new(coefficients)
+    }
+}
+
+impl<T> Polynomial<T> {
+    pub fn coefficients(&self) -> &[T] { &self.coefficients }
+
+    pub fn into_coefficients(self) -> Vec<T> { self.coefficients }
+
+    // `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> { self.coefficients.len().checked_sub(1) }
+
+    pub fn is_zero(&self) -> bool { self.coefficients.is_empty() }
+}
+
+impl<T: Semiring + Clone> Polynomial<T> {
+    // Horner's scheme with the point multiplied from the left
+    pub fn evaluate(&self, point: T) -> T {
+        self.coefficients.iter().rev()
+            .fold(zero(), |result, c| plus(multi(point.clone(), result), c.clone()))
+    }
+}
+
+impl<T: Display> Display for Polynomial<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+        for (degree, c) in self.coefficients.iter().enumerate().rev() {
+            if degree + 1 != self.coefficients.len() {
+                write!(f, "+")?;
+            }
+            match degree {
+                0 => write!(f, "{c}")?,
+                1 => write!(f, "{c}x")?,
+                _ => write!(f, "{c}x^{degree}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+
+impl<T: Semiring + Clone + PartialEq> Add for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (mut longer, shorter) = if self.coefficients.len() >= rhs.coefficients.len() { (self, rhs) } else { (rhs, self) };
+        for (c, s) in longer.coefficients.iter_mut().zip(shorter.coefficients) {
+            *c = plus(c.clone(), s);
+        }
+        Polynomial::new(longer.coefficients)
+    }
+}
+
+impl<T: Ring + Clone + PartialEq> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Self::Output {
+        Polynomial { coefficients: self.coefficients.into_iter().map(unary_minus).collect() }
+    }
+}
+
+impl<T: Ring + Clone + PartialEq> Sub for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: convolution::Convolution + PartialEq> Mul for Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Polynomial::new(T::convolve(&self.coefficients, &rhs.coefficients))
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::Div;
+use crate::polynomial::transform::{convolve_by_transform, RootOfUnity};
+use crate::structures::ring_like::{multi, one, plus, Ring, RingWithOne, Semiring, unary_minus, zero};
+
+// below these lengths of the shorter operand the asymptotically faster algorithms do not pay off
+pub const KARATSUBA_THRESHOLD: usize = 32;
+pub const TOOM3_THRESHOLD: usize = 192;
+pub const TRANSFORM_THRESHOLD: usize = 64;
+
+// polynomial multiplication, every coefficient type picks its algorithm, schoolbook by default
+pub trait Convolution: Semiring + Clone {
+    fn convolve(a: &[Self], b: &[Self]) -> Vec<Self> { schoolbook(a, b) }
+}
+
+pub fn convolve<T: Convolution>(a: &[T], b: &[T]) -> Vec<T> { T::convolve(a, b) }
+
+// keeps the order of factors, so it is valid for non-commutative coefficients
+pub fn schoolbook<T: Semiring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut result = Vec::<T>::with_capacity(a.len() + b.len() - 1);
+    result.resize_with(a.len() + b.len() - 1, zero);
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] = plus(result[i + j].clone(), multi(x.clone(), y.clone()));
+        }
+    }
+    result
+}
+
+// transform-based for long operands when the needed root of unity exists, Karatsuba otherwise
+pub fn karatsuba_or_transform<T: RootOfUnity>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.len().min(b.len()) < TRANSFORM_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    convolve_by_transform(a, b).unwrap_or_else(|| karatsuba(a, b))
+}
+
+fn split<T>(values: &[T], at: usize) -> (&[T], &[T]) {
+    values.split_at(at.min(values.len()))
+}
+
+fn split3<T>(values: &[T], at: usize) -> (&[T], &[T], &[T]) {
+    let (first, rest) = split(values, at);
+    let (second, third) = split(rest, at);
+    (first, second, third)
+}
+
+fn add_into<T: Semiring + Clone>(target: &mut [T], values: &[T], offset: usize) {
+    for (t, v) in target[offset..].iter_mut().zip(values) {
+        *t = plus(t.clone(), v.clone());
+    }
+}
+
+fn sum<T: Semiring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let (mut longer, shorter) = if a.len() >= b.len() { (a.to_vec(), b) } else { (b.to_vec(), a) };
+    add_into(&mut longer, shorter, 0);
+    longer
+}
+
+fn difference<T: Ring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    sum(a, &b.iter().cloned().map(unary_minus).collect::<Vec<_>>())
+}
+
+// also valid for non-commutative coefficients since the order of factors is kept
+pub fn karatsuba<T: Ring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    let half = a.len().max(b.len()) / 2;
+    let ((a0, a1), (b0, b1)) = (split(a, half), split(b, half));
+    let low = karatsuba(a0, b0);
+    let high = karatsuba(a1, b1);
+    let middle = karatsuba(&sum(a0, a1), &sum(b0, b1));
+    let middle = difference(&difference(&middle, &low), &high);
+    let mut result = Vec::<T>::with_capacity(a.len() + b.len() - 1);
+    result.resize_with(a.len() + b.len() - 1, zero);
+    add_into(&mut result, &low, 0);
+    add_into(&mut result, &middle, half);
+    add_into(&mut result, &high, 2 * half);
+    result
+}
+
+// Toom–Cook with evaluation at `0, 1, -1, -2, ∞`, interpolation divides exactly by 2 and 3
+pub fn toom3<T: RingWithOne + Clone + Div<Output=T>>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.len().min(b.len()) < TOOM3_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    let third = a.len().max(b.len()).div_ceil(3);
+    let two = plus(one::<T>(), one());
+    let three = plus(two.clone(), one());
+    let scale = |values: &[T], factor: &T| values.iter().map(|v| multi(factor.clone(), v.clone())).collect::<Vec<_>>();
+    let divide = |values: Vec<T>, divisor: &T| values.into_iter().map(|v| v / divisor.clone()).collect::<Vec<_>>();
+    let evaluations = |(p0, p1, p2): (&[T], &[T], &[T])| {
+        let even = sum(p0, p2);
+        let at_one = sum(&even, p1);
+        let at_minus_one = difference(&even, p1);
+        let at_minus_two = sum(&difference(p0, &scale(p1, &two)), &scale(p2, &plus(two.clone(), two.clone())));
+        [p0.to_vec(), at_one, at_minus_one, at_minus_two, p2.to_vec()]
+    };
+    let [a0, a1, am1, am2, a_inf] = evaluations(split3(a, third));
+    let [b0, b1, bm1, bm2, b_inf] = evaluations(split3(b, third));
+    let r0 = toom3(&a0, &b0);
+    let r1 = toom3(&a1, &b1);
+    let rm1 = toom3(&am1, &bm1);
+    let rm2 = toom3(&am2, &bm2);
+    let r_inf = toom3(&a_inf, &b_inf);
+    // Bodrato's interpolation sequence
+    let c3 = divide(difference(&rm2, &r1), &three);
+    let c1 = divide(difference(&r1, &rm1), &two);
+    let c2 = difference(&rm1, &r0);
+    let c3 = sum(&divide(difference(&c2, &c3), &two), &scale(&r_inf, &two));
+    let c2 = difference(&sum(&c2, &c1), &r_inf);
+    let c1 = difference(&c1, &c3);
+    let mut result = Vec::<T>::with_capacity(a.len() + b.len() + 4 * third);
+    result.resize_with(a.len() + b.len() + 4 * third, zero);
+    for (power, coefficients) in [r0, c1, c2, c3, r_inf].iter().enumerate() {
+        add_into(&mut result, coefficients, power * third);
+    }
+    result.truncate(a.len() + b.len() - 1);
+    result
+}
+
+pub fn schoolbook_or_karatsuba<T: Ring + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    karatsuba(a, b)
+}
+
+pub fn schoolbook_or_toom3<T: RingWithOne + Clone + Div<Output=T>>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    toom3(a, b)
+}
@@ -0,0 +1,85 @@
+use crate::power::unsigned::pow;
+use crate::structures::ring_like::{CommutativeRingWithOne, minus, multi, one, plus, try_inverse, UnitDetection, zero};
+
+// number-theoretic transform over `Zmod<P>`, FFT over complex numbers, the inverse also needs the order to be a unit
+pub trait RootOfUnity: CommutativeRingWithOne + UnitDetection + Clone {
+    // some `ω` with `ω^(n/2) = -1`, so `ωⁿ = 1` and `Σⱼ ωʲᵏ = 0` for `0 < k < n`, `order` is a power of two
+    fn root_of_unity(order: usize) -> Option<Self>;
+}
+
+fn from_length<T: CommutativeRingWithOne + Clone>(length: usize) -> T {
+    let mut result = one::<T>();
+    for _ in 0..length.trailing_zeros() {
+        result = plus(result.clone(), result);
+    }
+    result
+}
+
+// in-place radix-2 Cooley–Tukey: `values[k] ← Σ values[j]·rootʲᵏ`
+fn transform_with<T: CommutativeRingWithOne + Clone>(values: &mut [T], root: T) {
+    let length = values.len();
+    assert!(length.is_power_of_two(), "Transform length must be a power of two");
+    let bits = length.trailing_zeros();
+    for i in 0..length {
+        let j = i.reverse_bits().checked_shr(usize::BITS - bits).unwrap_or(0);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < length {
+        let step = pow(root.clone(), (length / (2 * half)) as u64);
+        let mut twiddles = Vec::with_capacity(half);
+        twiddles.push(one::<T>());
+        for k in 1..half {
+            twiddles.push(multi(twiddles[k - 1].clone(), step.clone()));
+        }
+        for start in (0..length).step_by(2 * half) {
+            for (k, twiddle) in twiddles.iter().enumerate() {
+                let u = values[start + k].clone();
+                let v = multi(values[start + k + half].clone(), twiddle.clone());
+                values[start + k] = plus(u.clone(), v.clone());
+                values[start + k + half] = minus(u, v);
+            }
+        }
+        half *= 2;
+    }
+}
+
+// `None` when the ring has no root of unity of order `values.len()`
+pub fn transform<T: RootOfUnity>(values: &mut [T]) -> Option<()> {
+    let root = T::root_of_unity(values.len())?;
+    transform_with(values, root);
+    Some(())
+}
+
+pub fn inverse_transform<T: RootOfUnity>(values: &mut [T]) -> Option<()> {
+    let root = T::root_of_unity(values.len())?;
+    let inverse_root = try_inverse(root)?;
+    let scale = try_inverse(from_length::<T>(values.len()))?;
+    transform_with(values, inverse_root);
+    for value in values.iter_mut() {
+        *value = multi(value.clone(), scale.clone());
+    }
+    Some(())
+}
+
+pub fn convolve_by_transform<T: RootOfUnity>(a: &[T], b: &[T]) -> Option<Vec<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(vec![]);
+    }
+    let result_length = a.len() + b.len() - 1;
+    let length = result_length.next_power_of_two();
+    let padded = |values: &[T]| {
+        let mut padded = values.to_vec();
+        padded.resize_with(length, zero);
+        padded
+    };
+    let (mut a, mut b) = (padded(a), padded(b));
+    transform(&mut a)?;
+    transform(&mut b)?;
+    let mut product = a.into_iter().zip(b).map(|(x, y)| multi(x, y)).collect::<Vec<_>>();
+    inverse_transform(&mut product)?;
+    product.truncate(result_length);
+    Some(product)
+}
@@ -1,24 +1,63 @@
 
-pub mod unsigned {
-    use crate::structures::ring_like::{RingWithOne, one, multi};
+pub mod instance {
+    use crate::structures::instances::{GroupInstance, MonoidInstance};
 
-    pub fn pow<T: RingWithOne + Clone>(base: T, exponent: u64) -> T {
+    pub fn pow<S: MonoidInstance<T>, T: Clone>(structure: &S, base: T, exponent: u64) -> T {
         if exponent == 0 {
-            one()
-        } else if exponent % 2 == 0 {
-            let t = pow(base, exponent / 2);
-            multi(t.clone(), t)
+            structure.identity()
+        } else if exponent.is_multiple_of(2) {
+            let t = pow(structure, base, exponent / 2);
+            structure.op(t.clone(), t)
         } else {
-            multi(pow(base.clone(), exponent - 1), base)
+            structure.op(pow(structure, base.clone(), exponent - 1), base)
         }
     }
+
+    pub fn signed_pow<S: GroupInstance<T>, T: Clone>(structure: &S, base: T, exponent: i64) -> T {
+        let power = pow(structure, base, exponent.unsigned_abs());
+        if exponent < 0 { structure.inverse(power) } else { power }
+    }
+}
+
+pub mod monoid {
+    use crate::structures::group_like::Monoid;
+    use crate::structures::instances::Static;
+
+    pub fn pow<G: Monoid<T>, T: Clone>(base: T, exponent: u64) -> T {
+        super::instance::pow(&Static::<G>::new(), base, exponent)
+    }
+}
+
+pub mod group {
+    use crate::structures::group_like::Group;
+    use crate::structures::instances::Static;
+
+    pub fn pow<G: Group<T>, T: Clone>(base: T, exponent: i64) -> T {
+        super::instance::signed_pow(&Static::<G>::new(), base, exponent)
+    }
+}
+
+pub mod unsigned {
+    use crate::structures::ring_like::{RingWithOne, Semiring};
+    use super::monoid;
+
+    pub fn pow<T: RingWithOne + Clone>(base: T, exponent: u64) -> T {
+        monoid::pow::<<T as Semiring>::Multi, T>(base, exponent)
+    }
 }
 
 pub mod signed {
-    use crate::structures::ring_like::{Field, reciprocal};
+    use crate::structures::ring_like::{DivisionByZero, DivisionRing, reciprocal, Semiring, try_reciprocal, UnitDetection};
+    use super::monoid;
     use super::unsigned::pow as u_pow;
 
-    pub fn pow<T: Field + Clone>(base: T, exponent: i64) -> T {
+    // fails for negative powers of non-units instead of leaving them implementation defined
+    pub fn checked_pow<T: UnitDetection + Clone>(base: T, exponent: i64) -> Result<T, DivisionByZero> {
+        let base = if exponent < 0 { try_reciprocal(base)? } else { base };
+        Ok(monoid::pow::<<T as Semiring>::Multi, T>(base, exponent.unsigned_abs()))
+    }
+
+    pub fn pow<T: DivisionRing + Clone>(base: T, exponent: i64) -> T {
         if exponent == i64::MIN {
             u_pow(pow(base, exponent / 2), 2)
         } else if exponent < 0 {
@@ -29,6 +68,25 @@ pub mod signed {
     }
 }
 
+pub mod modular {
+    pub const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+        (a as u128 * b as u128 % modulus as u128) as u64
+    }
+
+    pub const fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+        let mut base = base % modulus;
+        let mut result = 1 % modulus;
+        while exponent > 0 {
+            if !exponent.is_multiple_of(2) {
+                result = mul_mod(result, base, modulus);
+            }
+            base = mul_mod(base, base, modulus);
+            exponent /= 2;
+        }
+        result
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -42,4 +100,4 @@ mod tests {
         assert_eq!(signed::pow(2.0, 0), 1.0);
         assert_eq!(signed::pow(2.0, -1), 0.5);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,291 @@
+use num::{BigInt, BigUint, Integer, One, Signed, Zero};
+use num::integer::Roots;
+use crate::power::modular::{mul_mod, pow_mod};
+
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// `n - 1 = d·2ˢ`, `n` is odd
+const fn strong_probable_prime(n: u64, base: u64) -> bool {
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut x = pow_mod(base, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    let mut round = 1;
+    while round < s {
+        x = mul_mod(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+        round += 1;
+    }
+    false
+}
+
+// deterministic Miller–Rabin, the first 12 prime bases suffice below 2⁶⁴, `const` to check moduli at compile time
+pub const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        if n.is_multiple_of(SMALL_PRIMES[i]) {
+            return n == SMALL_PRIMES[i];
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        if !strong_probable_prime(n, SMALL_PRIMES[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+
+// Jacobi symbol `(a / n)` for odd positive `n`
+fn jacobi(a: &BigInt, n: &BigUint) -> i32 {
+    let mut n = BigInt::from(n.clone());
+    let mut a = a.mod_floor(&n);
+    let mut result = 1;
+    while !a.is_zero() {
+        while a.is_even() {
+            a /= 2;
+            let r = &n % 8u32;
+            if r == BigInt::from(3) || r == BigInt::from(5) {
+                result = -result;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if (&a % 4u32) == BigInt::from(3) && (&n % 4u32) == BigInt::from(3) {
+            result = -result;
+        }
+        a = a.mod_floor(&n);
+    }
+    if n.is_one() { result } else { 0 }
+}
+
+fn strong_probable_prime_big(n: &BigUint, base: &BigUint) -> bool {
+    let minus_one = n - 1u32;
+    let s = minus_one.trailing_zeros().unwrap();
+    let mut x = base.modpow(&(&minus_one >> s), n);
+    if x.is_one() || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x % n;
+        if x == minus_one {
+            return true;
+        }
+    }
+    false
+}
+
+// strong Lucas test with Selfridge's parameters `P = 1`, `Q = (1 - D) / 4`
+fn strong_lucas_probable_prime(n: &BigUint) -> bool {
+    if Roots::sqrt(n).pow(2) == *n {
+        return false;
+    }
+    let mut d = BigInt::from(5);
+    loop {
+        match jacobi(&d, n) {
+            -1 => break,
+            0 if d.abs() != BigInt::from(n.clone()) => return false,
+            _ => d = if d.is_positive() { -d - 2 } else { -d + 2 },
+        }
+    }
+    let modulus = BigInt::from(n.clone());
+    let q = ((BigInt::one() - &d) / BigInt::from(4)).mod_floor(&modulus);
+    let half = |x: BigInt| {
+        let x = x.mod_floor(&modulus);
+        let x: BigInt = if x.is_odd() { x + &modulus } else { x };
+        x >> 1
+    };
+    let plus_one = n + 1u32;
+    let s = plus_one.trailing_zeros().unwrap();
+    let odd = &plus_one >> s;
+    let (mut u, mut v, mut q_power) = (BigInt::one(), BigInt::one(), q.clone());
+    for bit in (0..odd.bits() - 1).rev() {
+        u = (&u * &v).mod_floor(&modulus);
+        v = (&v * &v - &q_power * BigInt::from(2)).mod_floor(&modulus);
+        q_power = (&q_power * &q_power).mod_floor(&modulus);
+        if odd.bit(bit) {
+            (u, v) = (half(&u + &v), half(&d * &u + &v));
+            q_power = (&q_power * &q).mod_floor(&modulus);
+        }
+    }
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = (&v * &v - &q_power * BigInt::from(2)).mod_floor(&modulus);
+        if v.is_zero() {
+            return true;
+        }
+        q_power = (&q_power * &q_power).mod_floor(&modulus);
+    }
+    false
+}
+
+// Baillie–PSW, no composite passing it is known
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    if let Ok(small) = u64::try_from(n) {
+        return is_prime(small);
+    }
+    if SMALL_PRIMES.iter().any(|&p| (n % p).is_zero()) {
+        return false;
+    }
+    strong_probable_prime_big(n, &BigUint::from(2u32)) && strong_lucas_probable_prime(n)
+}
+
+
+// a non-trivial divisor of an odd composite `n` by Brent's variant of Pollard's rho
+fn pollard_rho(n: u64) -> u64 {
+    let step = |x: u64, c: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+    for c in 1.. {
+        let (mut y, mut divisor) = (2, 1);
+        let mut length = 1;
+        while divisor == 1 {
+            let x = y;
+            for _ in 0..length {
+                y = step(y, c);
+                divisor = (x.abs_diff(y)).gcd(&n);
+                if divisor != 1 {
+                    break;
+                }
+            }
+            length *= 2;
+        }
+        if divisor != n {
+            return divisor;
+        }
+    }
+    unreachable!()
+}
+
+fn collect_factors(n: u64, factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        factors.push(n);
+        return;
+    }
+    let divisor = pollard_rho(n);
+    collect_factors(divisor, factors);
+    collect_factors(n / divisor, factors);
+}
+
+const TRIAL_DIVISION_BOUND: u64 = 1000;
+
+// prime factorization as `(prime, exponent)` pairs in increasing order, empty for 0 and 1
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n == 0 {
+        return vec![];
+    }
+    for p in 2..=TRIAL_DIVISION_BOUND {
+        if p * p > n {
+            break;
+        }
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    collect_factors(n, &mut factors);
+    factors.sort_unstable();
+    let mut result: Vec<(u64, u32)> = Vec::new();
+    for p in factors {
+        match result.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => result.push((p, 1)),
+        }
+    }
+    result
+}
+
+// Euler's totient, `φ(0) = 0`
+pub fn totient(n: u64) -> u64 {
+    factorize(n).into_iter().fold(n, |result, (p, _)| result / p * (p - 1))
+}
+
+// Carmichael's function `λ(n)`, the exponent of the multiplicative group modulo `n`, `λ(0) = 0`
+pub fn carmichael(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n).into_iter()
+        .map(|(p, k)| match (p, k) {
+            (2, 1) => 1,
+            (2, 2) => 2,
+            (2, _) => 1 << (k - 2),
+            _ => p.pow(k - 1) * (p - 1),
+        })
+        .fold(1, |result, x| result.lcm(&x))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use crate::power::modular::pow_mod;
+    use crate::primality::{carmichael, factorize, is_prime, is_probable_prime, strong_lucas_probable_prime, totient};
+
+    #[test]
+    fn strong_lucas() {
+        let pseudoprimes = (5..20000u32)
+            .filter(|&n| n % 2 == 1 && n % 3 != 0 && n % 5 != 0)
+            .filter(|&n| strong_lucas_probable_prime(&BigUint::from(n)) != is_prime(n as u64))
+            .collect::<Vec<_>>();
+        assert_eq!(pseudoprimes, [5459, 5777, 10877, 16109, 18971]);
+    }
+
+    #[test]
+    fn miller_rabin() {
+        let primes = (0..100).filter(|&n| is_prime(n)).collect::<Vec<_>>();
+        assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97]);
+        assert!(is_prime(4611686018427387847));
+        assert!(is_prime(18446744073709551557));
+        // strong pseudoprimes to several small bases
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(3825123056546413051));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn baillie_psw() {
+        let big = |s: &str| s.parse::<BigUint>().unwrap();
+        assert!(is_probable_prime(&big("170141183460469231731687303715884105727")));
+        assert!(is_probable_prime(&big("18446744073709551557")));
+        assert!(is_probable_prime(&big("618970019642690137449562111")));
+        assert!(!is_probable_prime(&big("340282366920938463463374607431768211457")));
+        // a strong pseudoprime to every prime base below 41
+        assert!(!is_probable_prime(&big("3317044064679887385961981")));
+        assert!(!is_probable_prime(&(big("18446744073709551557") * big("4611686018427387847"))));
+        assert!(!is_probable_prime(&big("4611686018427387847").pow(2)));
+    }
+
+    #[test]
+    fn factorization() {
+        assert_eq!(factorize(1), []);
+        assert_eq!(factorize(360), [(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(4611686018427387847 * 3), [(3, 1), (4611686018427387847, 1)]);
+        assert_eq!(factorize(4294967291 * 4294967279), [(4294967279, 1), (4294967291, 1)]);
+        assert_eq!(factorize(1000003u64.pow(3)), [(1000003, 3)]);
+        assert_eq!(factorize(u64::MAX), [(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]);
+    }
+
+    #[test]
+    fn totient_and_carmichael() {
+        assert_eq!((1..=12).map(totient).collect::<Vec<_>>(), [1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]);
+        assert_eq!((1..=12).map(carmichael).collect::<Vec<_>>(), [1, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2]);
+        assert_eq!(carmichael(561), 80);
+        let n = 4294967291 * 65537;
+        assert!((2..50).all(|a| pow_mod(a, carmichael(n), n) == 1 || a == 65537));
+        assert_eq!(totient(n), 4294967290 * 65536);
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+// `re + i·𝐢 + j·𝐣 + k·𝐤` with `𝐢² = 𝐣² = 𝐤² = 𝐢𝐣𝐤 = -1`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash, Default)]
+pub struct Quaternion<T> {
+    pub re: T,
+    pub i: T,
+    pub j: T,
+    pub k: T,
+}
+
+impl<T> Quaternion<T> {
+    pub fn new(re: T, i: T, j: T, k: T) -> Quaternion<T> { Quaternion { re, i, j, k } }
+}
+
+impl<T: Neg<Output=T>> Quaternion<T> {
+    pub fn conjugate(self) -> Quaternion<T> { Quaternion::new(self.re, -self.i, -self.j, -self.k) }
+}
+
+impl<T: Mul<Output=T> + Add<Output=T> + Clone> Quaternion<T> {
+    pub fn norm_sqr(&self) -> T {
+        let square = |x: &T| x.clone() * x.clone();
+        square(&self.re) + square(&self.i) + square(&self.j) + square(&self.k)
+    }
+}
+
+impl<T: Display> Display for Quaternion<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}+{}i+{}j+{}k", self.re, self.i, self.j, self.k)
+    }
+}
+
+
+impl<T: Add<Output=T>> Add for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion::new(self.re + rhs.re, self.i + rhs.i, self.j + rhs.j, self.k + rhs.k)
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quaternion::new(self.re - rhs.re, self.i - rhs.i, self.j - rhs.j, self.k - rhs.k)
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.re, -self.i, -self.j, -self.k)
+    }
+}
+
+// Hamilton product, not commutative
+impl<T: Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Clone> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let Quaternion { re: a1, i: b1, j: c1, k: d1 } = self;
+        let Quaternion { re: a2, i: b2, j: c2, k: d2 } = rhs;
+        Quaternion::new(
+            a1.clone() * a2.clone() - b1.clone() * b2.clone() - c1.clone() * c2.clone() - d1.clone() * d2.clone(),
+            a1.clone() * b2.clone() + b1.clone() * a2.clone() + c1.clone() * d2.clone() - d1.clone() * c2.clone(),
+            a1.clone() * c2.clone() - b1.clone() * d2.clone() + c1.clone() * a2.clone() + d1.clone() * b2.clone(),
+            a1 * d2 + b1 * c2 - c1 * b2 + d1 * a2,
+        )
+    }
+}
@@ -0,0 +1,200 @@
+use crate::implementations::finite_matrix::FiniteMatrixMul;
+use crate::matrix::FiniteMatrix;
+use crate::matrix::finite::Shape;
+use crate::power::unsigned::pow;
+use crate::structures::basics::Magma;
+use crate::structures::ring_like::{Field, minus, multi, one, plus, reciprocal, unary_minus, zero};
+
+// polynomials as coefficient vectors, lowest degree first, without trailing zeros
+fn trim<T: Field + PartialEq>(mut polynomial: Vec<T>) -> Vec<T> {
+    while polynomial.last().is_some_and(|c| *c == zero()) {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+fn evaluate<T: Field + Clone>(polynomial: &[T], point: &T) -> T {
+    polynomial.iter().rev().fold(zero(), |result, c| plus(multi(result, point.clone()), c.clone()))
+}
+
+// `None` when the remainder is not zero
+fn divide_exactly<T: Field + Clone + PartialEq>(dividend: Vec<T>, divisor: Vec<T>) -> Option<Vec<T>> {
+    let mut remainder = trim(dividend);
+    let divisor = trim(divisor);
+    let leading_inverse = reciprocal(divisor.last()?.clone());
+    if remainder.len() < divisor.len() {
+        return remainder.is_empty().then(Vec::new);
+    }
+    let mut quotient = vec![zero(); remainder.len() - divisor.len() + 1];
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let factor = multi(remainder.last().unwrap().clone(), leading_inverse.clone());
+        for (i, c) in divisor.iter().enumerate() {
+            remainder[shift + i] = minus(remainder[shift + i].clone(), multi(factor.clone(), c.clone()));
+        }
+        quotient[shift] = factor;
+        remainder = trim(remainder);
+    }
+    remainder.is_empty().then_some(quotient)
+}
+
+fn vandermonde<T: Field + Clone>(points: &[T], degree: usize) -> FiniteMatrix<T> {
+    let shape = Shape { height: points.len(), width: degree };
+    FiniteMatrix::from_indexed_generator(shape, |row, column| pow(points[row].clone(), column as u64))
+}
+
+
+// systematic code, a codeword holds the values at `points` of the polynomial of degree below `data_length` through the data at the first points
+#[derive(Clone, Debug)]
+pub struct ReedSolomon<T> {
+    points: Vec<T>,
+    data_length: usize,
+    generator: FiniteMatrix<T>,
+}
+
+impl<T: Field + Clone + PartialEq> ReedSolomon<T> {
+    // panics when the points are not distinct or there are fewer points than data symbols
+    pub fn new(points: Vec<T>, data_length: usize) -> ReedSolomon<T> {
+        assert!(0 < data_length && data_length <= points.len(), "Code dimension must be between 1 and the code length");
+        // all of them, a repeated point after the data points repeats a symbol and lowers the minimum distance
+        assert!(points.iter().enumerate().all(|(i, point)| !points[..i].contains(point)), "Evaluation points must be distinct");
+        let encoding = vandermonde(&points, data_length);
+        let data_points = vandermonde(&points[..data_length], data_length);
+        let interpolation = data_points.inverse().expect("Vandermonde matrices of distinct points are invertible");
+        let generator = FiniteMatrixMul::operation(encoding, interpolation);
+        ReedSolomon { points, data_length, generator }
+    }
+
+    pub fn code_length(&self) -> usize { self.points.len() }
+
+    pub fn data_length(&self) -> usize { self.data_length }
+
+    // `code_length × data_length` matrix whose first rows form the identity
+    pub fn generator(&self) -> &FiniteMatrix<T> { &self.generator }
+
+    pub fn encode(&self, data: &[T]) -> Vec<T> {
+        assert_eq!(data.len(), self.data_length);
+        (0..self.code_length())
+            .map(|row| (0..self.data_length)
+                .map(|column| multi(self.generator[(row, column)].clone(), data[column].clone()))
+                .fold(zero(), plus))
+            .collect()
+    }
+
+    // recovers the data from any `data_length` known symbols
+    pub fn decode_erasures(&self, received: &[Option<T>]) -> Option<Vec<T>> {
+        assert_eq!(received.len(), self.code_length());
+        let known = received.iter().enumerate()
+            .filter_map(|(index, symbol)| symbol.clone().map(|symbol| (index, symbol)))
+            .take(self.data_length)
+            .collect::<Vec<_>>();
+        if known.len() < self.data_length {
+            return None;
+        }
+        let shape = Shape { height: self.data_length, width: self.data_length };
+        let rows = FiniteMatrix::from_indexed_generator(shape, |row, column| self.generator[(known[row].0, column)].clone());
+        let inverse = rows.inverse()?;
+        Some((0..self.data_length)
+            .map(|row| (0..self.data_length)
+                .map(|column| multi(inverse[(row, column)].clone(), known[column].1.clone()))
+                .fold(zero(), plus))
+            .collect())
+    }
+
+    // Berlekamp–Welch decoding, corrects up to `(code_length - data_length) / 2` wrong symbols
+    pub fn correct_errors(&self, received: &[T]) -> Option<Vec<T>> {
+        assert_eq!(received.len(), self.code_length());
+        let errors = (self.code_length() - self.data_length) / 2;
+        let q_length = errors + self.data_length;
+        // unknowns are the coefficients of `Q` and the non-leading ones of the monic error locator `E`,
+        // equations are `Q(xᵢ) - rᵢ·E(xᵢ) = 0`
+        let shape = Shape { height: self.code_length(), width: q_length + errors };
+        let system = FiniteMatrix::from_indexed_generator(shape, |row, column| {
+            let x = self.points[row].clone();
+            if column < q_length {
+                pow(x, column as u64)
+            } else {
+                let power = pow(x, (column - q_length) as u64);
+                unary_minus(multi(received[row].clone(), power))
+            }
+        });
+        let rhs = (0..self.code_length())
+            .map(|row| multi(received[row].clone(), pow(self.points[row].clone(), errors as u64)))
+            .collect::<Vec<_>>();
+        let solution = system.solve(&rhs)?;
+        let q = solution[..q_length].to_vec();
+        let mut locator = solution[q_length..].to_vec();
+        locator.push(one());
+        let message = divide_exactly(q, locator)?;
+        let codeword = self.points.iter().map(|x| evaluate(&message, x)).collect::<Vec<_>>();
+        let mismatches = codeword.iter().zip(received).filter(|(a, b)| a != b).count();
+        (mismatches <= errors).then(|| codeword[..self.data_length].to_vec())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::galois::GaloisField;
+    use crate::modular::Zp;
+    use crate::reed_solomon::ReedSolomon;
+
+    type Gf256 = GaloisField<2, 8>;
+
+    fn code() -> ReedSolomon<Gf256> {
+        ReedSolomon::new((0..10).map(Gf256::from_index).collect(), 4)
+    }
+
+    fn bytes(values: &[u64]) -> Vec<Gf256> {
+        values.iter().map(|&x| Gf256::from_index(x)).collect()
+    }
+
+    #[test]
+    fn systematic() {
+        let code = code();
+        let data = bytes(&[0x48, 0x65, 0x6c, 0x6f]);
+        let codeword = code.encode(&data);
+        assert_eq!(codeword.len(), 10);
+        assert_eq!(codeword[..4], data[..]);
+        assert_eq!(code.correct_errors(&codeword), Some(data));
+    }
+
+    #[test]
+    fn erasures() {
+        let code = code();
+        let data = bytes(&[1, 2, 3, 4]);
+        let codeword = code.encode(&data);
+        let mut received = codeword.into_iter().map(Some).collect::<Vec<_>>();
+        for index in [0, 2, 3, 5, 8, 9] {
+            received[index] = None;
+        }
+        assert_eq!(code.decode_erasures(&received), Some(data));
+        received[1] = None;
+        assert_eq!(code.decode_erasures(&received), None);
+    }
+
+    #[test]
+    fn errors() {
+        let code = code();
+        let data = bytes(&[0xde, 0xad, 0xbe, 0xef]);
+        let mut received = code.encode(&data);
+        received[0] = Gf256::from_index(0);
+        received[7] = Gf256::from_index(0x42);
+        received[9] = Gf256::from_index(0x42);
+        assert_eq!(code.correct_errors(&received), Some(data));
+    }
+
+    #[test]
+    fn prime_field() {
+        type Z = Zp<929>;
+        let code = ReedSolomon::new((1..=7).map(Z::new).collect(), 3);
+        let data = vec![Z::new(3), Z::new(2), Z::new(1)];
+        let mut received = code.encode(&data);
+        received[1] = Z::new(0);
+        received[4] = Z::new(5);
+        assert_eq!(code.correct_errors(&received), Some(data.clone()));
+        received[5] = Z::new(5);
+        assert_ne!(code.correct_errors(&received), Some(data));
+        assert!(std::panic::catch_unwind(|| ReedSolomon::new(vec![Z::new(1), Z::new(2), Z::new(3), Z::new(2)], 2)).is_err());
+    }
+}
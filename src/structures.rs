@@ -1,3 +1,8 @@
 pub mod basics;
 pub mod group_like;
-pub mod ring_like;
\ No newline at end of file
+pub mod ring_like;
+pub mod module_like;
+pub mod lattice_like;
+pub mod cayley_table;
+pub mod instances;
+pub mod homomorphism;
\ No newline at end of file
@@ -12,4 +12,12 @@ pub trait Invertible<T> {
 
 pub trait WithIdentityElement<T> {
     fn identity() -> T;
-}
\ No newline at end of file
+}
+
+// `operation(x, x) = x`
+pub trait Idempotent {}
+
+// the inverse of the elements that have one
+pub trait TryInvertible<T> {
+    fn try_inverse(operand: T) -> Option<T>;
+}
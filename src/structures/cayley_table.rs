@@ -0,0 +1,250 @@
+use std::fmt::{Display, Formatter};
+use crate::structures::basics::Magma;
+
+// finite magma on `0..size` given at runtime, `table[a · size + b] = a · b`
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct CayleyTable {
+    size: usize,
+    table: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum CayleyTableError {
+    RaggedRow(usize),
+    OutOfRange { row: usize, column: usize },
+    NotClosed { row: usize, column: usize },
+}
+
+impl Display for CayleyTableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CayleyTableError::RaggedRow(row) => write!(f, "Row {row} differs in length from the number of rows"),
+            CayleyTableError::OutOfRange { row, column } => write!(f, "Entry ({row}, {column}) is not an element"),
+            CayleyTableError::NotClosed { row, column } => write!(f, "Product of elements {row} and {column} is not among the elements"),
+        }
+    }
+}
+
+impl std::error::Error for CayleyTableError {}
+
+// the names of `group_like`, a table is `Invertible` when it is a Latin square and, if it has an identity, all inverses are two-sided
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub enum Classification {
+    Magma,
+    CommutativeMagma,
+    Semigroup,
+    Quasigroup,
+    UnitaryMagma,
+    CommutativeSemigroup,
+    CommutativeQuasigroup,
+    CommutativeUnitaryMagma,
+    Loop,
+    InverseSemigroup,
+    Monoid,
+    CommutativeLoop,
+    CommutativeInverseSemigroup,
+    CommutativeMonoid,
+    Group,
+    CommutativeGroup,
+}
+
+impl Display for Classification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl CayleyTable {
+    pub fn new(rows: Vec<Vec<usize>>) -> Result<CayleyTable, CayleyTableError> {
+        let size = rows.len();
+        if let Some(row) = rows.iter().position(|row| row.len() != size) {
+            return Err(CayleyTableError::RaggedRow(row));
+        }
+        CayleyTable::from_operation(size, |a, b| rows[a][b])
+    }
+
+    pub fn from_operation(size: usize, operation: impl Fn(usize, usize) -> usize) -> Result<CayleyTable, CayleyTableError> {
+        let mut table = Vec::with_capacity(size * size);
+        for row in 0..size {
+            for column in 0..size {
+                let value = operation(row, column);
+                if value >= size {
+                    return Err(CayleyTableError::OutOfRange { row, column });
+                }
+                table.push(value);
+            }
+        }
+        Ok(CayleyTable { size, table })
+    }
+
+    // element `i` stands for `elements[i]`, which must be closed under `G::operation`
+    pub fn from_elements<G: Magma<T>, T: Clone + PartialEq>(elements: &[T]) -> Result<CayleyTable, CayleyTableError> {
+        let mut table = Vec::with_capacity(elements.len() * elements.len());
+        for (row, a) in elements.iter().enumerate() {
+            for (column, b) in elements.iter().enumerate() {
+                let product = G::operation(a.clone(), b.clone());
+                let index = elements.iter().position(|x| *x == product)
+                    .ok_or(CayleyTableError::NotClosed { row, column })?;
+                table.push(index);
+            }
+        }
+        Ok(CayleyTable { size: elements.len(), table })
+    }
+
+    pub fn size(&self) -> usize { self.size }
+
+    pub fn operation(&self, a: usize, b: usize) -> usize {
+        assert!(a < self.size && b < self.size, "Operands must be elements of the table");
+        self.table[a * self.size + b]
+    }
+
+    fn elements(&self) -> std::ops::Range<usize> { 0..self.size }
+
+    pub fn is_associative(&self) -> bool {
+        self.elements().all(|a| self.elements().all(|b| self.elements().all(|c| {
+            self.operation(self.operation(a, b), c) == self.operation(a, self.operation(b, c))
+        })))
+    }
+
+    pub fn is_commutative(&self) -> bool {
+        self.elements().all(|a| (0..a).all(|b| self.operation(a, b) == self.operation(b, a)))
+    }
+
+    pub fn identity(&self) -> Option<usize> {
+        self.elements().find(|&e| self.elements().all(|a| self.operation(e, a) == a && self.operation(a, e) == a))
+    }
+
+    // every row and column is a permutation, so `a · x = b` and `y · a = b` are uniquely solvable
+    pub fn is_latin_square(&self) -> bool {
+        let is_permutation = |value: &dyn Fn(usize) -> usize| {
+            let mut seen = vec![false; self.size];
+            self.elements().all(|b| !std::mem::replace(&mut seen[value(b)], true))
+        };
+        self.elements().all(|a| is_permutation(&|b| self.operation(a, b)) && is_permutation(&|b| self.operation(b, a)))
+    }
+
+    // the two-sided inverse with respect to the identity
+    pub fn inverse(&self, a: usize) -> Option<usize> {
+        let identity = self.identity()?;
+        self.elements().find(|&b| self.operation(a, b) == identity && self.operation(b, a) == identity)
+    }
+
+    pub fn into_group(self) -> Option<CayleyGroup> { CayleyGroup::new(self) }
+
+    pub fn classify(&self) -> Classification {
+        use Classification::*;
+        let associative = self.is_associative();
+        let commutative = self.is_commutative();
+        let identity = self.identity();
+        // a Latin square is a quasigroup, with an identity the inverses must also be two-sided for a loop or group
+        let invertible = self.is_latin_square() && identity.is_none_or(|_| self.elements().all(|a| self.inverse(a).is_some()));
+        let identity = identity.is_some();
+        match (associative, commutative, identity, invertible) {
+            (false, false, false, false) => Magma,
+            (false, true, false, false) => CommutativeMagma,
+            (true, false, false, false) => Semigroup,
+            (false, false, false, true) => Quasigroup,
+            (false, false, true, false) => UnitaryMagma,
+            (true, true, false, false) => CommutativeSemigroup,
+            (false, true, false, true) => CommutativeQuasigroup,
+            (false, true, true, false) => CommutativeUnitaryMagma,
+            (false, false, true, true) => Loop,
+            (true, false, false, true) => InverseSemigroup,
+            (true, false, true, false) => Monoid,
+            (false, true, true, true) => CommutativeLoop,
+            (true, true, false, true) => CommutativeInverseSemigroup,
+            (true, true, true, false) => CommutativeMonoid,
+            (true, false, true, true) => Group,
+            (true, true, true, true) => CommutativeGroup,
+        }
+    }
+}
+
+
+// a table checked to be a group, so that it carries the identity and inverses as instances
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct CayleyGroup {
+    table: CayleyTable,
+    identity: usize,
+    inverses: Vec<usize>,
+}
+
+impl CayleyGroup {
+    // `None` unless the table is associative with an identity and inverses
+    pub fn new(table: CayleyTable) -> Option<CayleyGroup> {
+        if !table.is_associative() {
+            return None;
+        }
+        let identity = table.identity()?;
+        let inverses = table.elements().map(|a| table.inverse(a)).collect::<Option<Vec<_>>>()?;
+        Some(CayleyGroup { table, identity, inverses })
+    }
+
+    pub fn table(&self) -> &CayleyTable { &self.table }
+
+    pub fn identity(&self) -> usize { self.identity }
+
+    pub fn inverse(&self, a: usize) -> usize { self.inverses[a] }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::implementations::permutation::Composition;
+    use crate::permutation::Permutation;
+    use crate::structures::cayley_table::{CayleyTable, CayleyTableError, Classification};
+
+    #[test]
+    fn classification() {
+        let cyclic = CayleyTable::from_operation(4, |a, b| (a + b) % 4).unwrap();
+        assert_eq!(cyclic.classify(), Classification::CommutativeGroup);
+        assert_eq!(cyclic.inverse(1), Some(3));
+        let subtraction = CayleyTable::from_operation(3, |a, b| (a + 3 - b) % 3).unwrap();
+        assert_eq!(subtraction.classify(), Classification::Quasigroup);
+        assert_eq!(subtraction.inverse(1), None);
+        let maximum = CayleyTable::from_operation(4, usize::max).unwrap();
+        assert_eq!(maximum.classify(), Classification::CommutativeMonoid);
+        let projection = CayleyTable::from_operation(3, |a, _| a).unwrap();
+        assert_eq!(projection.classify(), Classification::Semigroup);
+        let average = CayleyTable::from_operation(3, |a, b| (a + b) / 2).unwrap();
+        assert_eq!(average.classify(), Classification::CommutativeMagma);
+        let loop_table = CayleyTable::new(vec![
+            vec![0, 1, 2, 3, 4],
+            vec![1, 0, 3, 4, 2],
+            vec![2, 4, 0, 1, 3],
+            vec![3, 2, 4, 0, 1],
+            vec![4, 3, 1, 2, 0],
+        ]).unwrap();
+        assert_eq!(loop_table.classify(), Classification::Loop);
+        assert_eq!(loop_table.classify().to_string(), "Loop");
+        // `2 · 3 = 0` but `3 · 2 = 1`, so 2 has no two-sided inverse although every row and column is a permutation
+        let one_sided = CayleyTable::new(vec![
+            vec![0, 1, 2, 3, 4],
+            vec![1, 0, 3, 4, 2],
+            vec![2, 3, 4, 0, 1],
+            vec![3, 4, 1, 2, 0],
+            vec![4, 2, 0, 1, 3],
+        ]).unwrap();
+        assert!(one_sided.is_latin_square());
+        assert_eq!(one_sided.inverse(2), None);
+        assert_eq!(one_sided.classify(), Classification::UnitaryMagma);
+    }
+
+    #[test]
+    fn from_elements() {
+        let symmetric = ["()", "(0 1)", "(1 2)", "(0 2)", "(0 1 2)", "(0 2 1)"]
+            .map(|cycles| cycles.parse::<Permutation>().unwrap());
+        let table = CayleyTable::from_elements::<Composition, _>(&symmetric).unwrap();
+        assert_eq!(table.classify(), Classification::Group);
+        assert_eq!(table.identity(), Some(0));
+        assert_eq!(table.inverse(4), Some(5));
+        assert_eq!(CayleyTable::from_elements::<Composition, _>(&symmetric[..2]).map(|table| table.classify()), Ok(Classification::CommutativeGroup));
+        assert_eq!(CayleyTable::from_elements::<Composition, _>(&symmetric[1..3]), Err(CayleyTableError::NotClosed { row: 0, column: 0 }));
+    }
+
+    #[test]
+    fn invalid_tables() {
+        assert_eq!(CayleyTable::new(vec![vec![0, 1], vec![1]]), Err(CayleyTableError::RaggedRow(1)));
+        assert_eq!(CayleyTable::new(vec![vec![0, 1], vec![1, 2]]), Err(CayleyTableError::OutOfRange { row: 1, column: 1 }));
+    }
+}
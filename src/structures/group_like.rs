@@ -1,4 +1,4 @@
-use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::basics::{Associative, Commutative, Idempotent, Invertible, Magma, WithIdentityElement};
 
 
 pub trait CommutativeMagma<T> = Magma<T> + Commutative;
@@ -35,3 +35,10 @@ pub trait Group<T> = Magma<T> + WithIdentityElement<T> + Invertible<T> + Associa
 pub trait CommutativeGroup<T> = Magma<T> + WithIdentityElement<T> + Invertible<T> + Associative + Commutative;
 
 pub trait AbelGroup<T> = CommutativeGroup<T>;
+
+
+pub trait Band<T> = Magma<T> + Associative + Idempotent;
+
+pub trait Semilattice<T> = Magma<T> + Associative + Commutative + Idempotent;
+
+pub trait BoundedSemilattice<T> = Magma<T> + WithIdentityElement<T> + Associative + Commutative + Idempotent;
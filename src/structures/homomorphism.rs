@@ -0,0 +1,41 @@
+use crate::structures::ring_like::{multi, one, plus, SemiringWithOne, zero};
+
+// map preserving `plus`, `multi`, `zero` and `one`
+pub trait Homomorphism<A, B> {
+    fn apply(&self, value: A) -> B;
+}
+
+impl<A, B, F: Fn(A) -> B> Homomorphism<A, B> for F {
+    fn apply(&self, value: A) -> B { self(value) }
+}
+
+// the first law broken on the samples, with the operands breaking it
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum HomomorphismViolation<A> {
+    Zero,
+    One,
+    Plus(A, A),
+    Multi(A, A),
+}
+
+pub fn check_homomorphism<A, B, H>(homomorphism: &H, samples: &[A]) -> Result<(), HomomorphismViolation<A>>
+    where A: SemiringWithOne + Clone, B: SemiringWithOne + PartialEq, H: Homomorphism<A, B> {
+    if homomorphism.apply(zero()) != zero::<B>() {
+        return Err(HomomorphismViolation::Zero);
+    }
+    if homomorphism.apply(one()) != one::<B>() {
+        return Err(HomomorphismViolation::One);
+    }
+    for a in samples {
+        for b in samples {
+            let (x, y) = (a.clone(), b.clone());
+            if homomorphism.apply(plus(x.clone(), y.clone())) != plus(homomorphism.apply(x.clone()), homomorphism.apply(y.clone())) {
+                return Err(HomomorphismViolation::Plus(x, y));
+            }
+            if homomorphism.apply(multi(x.clone(), y.clone())) != multi(homomorphism.apply(x.clone()), homomorphism.apply(y.clone())) {
+                return Err(HomomorphismViolation::Multi(x, y));
+            }
+        }
+    }
+    Ok(())
+}
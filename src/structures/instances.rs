@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+use crate::structures::basics::{Associative, Commutative, Invertible, Magma, WithIdentityElement};
+use crate::structures::ring_like::Semiring;
+
+// instance-carrying counterparts of `basics`, the structure value may hold runtime parameters;
+// `Associative` and `Commutative` markers are shared with the static structures
+
+pub trait MagmaInstance<T> {
+    fn op(&self, operand1: T, operand2: T) -> T;
+}
+
+pub trait WithIdentityElementInstance<T> {
+    fn identity(&self) -> T;
+}
+
+pub trait InvertibleInstance<T> {
+    fn inverse(&self, operand: T) -> T;
+}
+
+
+pub trait SemigroupInstance<T> = MagmaInstance<T> + Associative;
+
+pub trait MonoidInstance<T> = MagmaInstance<T> + WithIdentityElementInstance<T> + Associative;
+
+pub trait CommutativeMonoidInstance<T> = MagmaInstance<T> + WithIdentityElementInstance<T> + Associative + Commutative;
+
+pub trait GroupInstance<T> = MagmaInstance<T> + WithIdentityElementInstance<T> + InvertibleInstance<T> + Associative;
+
+pub trait AbelGroupInstance<T> = MagmaInstance<T> + WithIdentityElementInstance<T> + InvertibleInstance<T> + Associative + Commutative;
+
+
+pub trait SemiringInstance<T> {
+    type Sum: CommutativeMonoidInstance<T>;
+    type Multi: SemigroupInstance<T>;
+
+    fn sum(&self) -> &Self::Sum;
+    fn multi(&self) -> &Self::Multi;
+}
+
+pub trait SemiringWithOneInstance<T> = SemiringInstance<T, Multi: MonoidInstance<T>>;
+
+pub trait RingInstance<T> = SemiringInstance<T, Sum: AbelGroupInstance<T>>;
+
+pub trait RingWithOneInstance<T> = SemiringInstance<T, Sum: AbelGroupInstance<T>, Multi: MonoidInstance<T>>;
+
+
+// a static structure `G` as an instance
+pub struct Static<G>(PhantomData<G>);
+
+impl<G> Static<G> {
+    pub fn new() -> Static<G> { Static(PhantomData) }
+}
+
+impl<G> Default for Static<G> {
+    fn default() -> Self { Static::new() }
+}
+
+impl<G> Clone for Static<G> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<G> Copy for Static<G> {}
+
+impl<G: Associative> Associative for Static<G> {}
+
+impl<G: Commutative> Commutative for Static<G> {}
+
+impl<T, G: Magma<T>> MagmaInstance<T> for Static<G> {
+    fn op(&self, operand1: T, operand2: T) -> T { G::operation(operand1, operand2) }
+}
+
+impl<T, G: WithIdentityElement<T>> WithIdentityElementInstance<T> for Static<G> {
+    fn identity(&self) -> T { G::identity() }
+}
+
+impl<T, G: Invertible<T>> InvertibleInstance<T> for Static<G> {
+    fn inverse(&self, operand: T) -> T { G::inverse(operand) }
+}
+
+// the structures of a `Semiring` type as an instance
+pub struct StaticSemiring<T: Semiring> {
+    sum: Static<T::Sum>,
+    multi: Static<T::Multi>,
+}
+
+impl<T: Semiring> StaticSemiring<T> {
+    pub fn new() -> StaticSemiring<T> { StaticSemiring { sum: Static::new(), multi: Static::new() } }
+}
+
+impl<T: Semiring> Default for StaticSemiring<T> {
+    fn default() -> Self { StaticSemiring::new() }
+}
+
+impl<T: Semiring> SemiringInstance<T> for StaticSemiring<T> {
+    type Sum = Static<T::Sum>;
+    type Multi = Static<T::Multi>;
+
+    fn sum(&self) -> &Self::Sum { &self.sum }
+    fn multi(&self) -> &Self::Multi { &self.multi }
+}
+
+
+// an instance without runtime state as a static structure, each call uses `I::default()`
+pub struct FromInstance<I>(PhantomData<I>);
+
+impl<I: Associative> Associative for FromInstance<I> {}
+
+impl<I: Commutative> Commutative for FromInstance<I> {}
+
+impl<T, I: MagmaInstance<T> + Default> Magma<T> for FromInstance<I> {
+    fn operation(operand1: T, operand2: T) -> T { I::default().op(operand1, operand2) }
+}
+
+impl<T, I: WithIdentityElementInstance<T> + Default> WithIdentityElement<T> for FromInstance<I> {
+    fn identity() -> T { I::default().identity() }
+}
+
+impl<T, I: InvertibleInstance<T> + Default> Invertible<T> for FromInstance<I> {
+    fn inverse(operand: T) -> T { I::default().inverse(operand) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::structures::basics::{Magma, WithIdentityElement};
+    use crate::structures::group_like::Group;
+    use crate::structures::instances::{FromInstance, GroupInstance, RingWithOneInstance, Static, StaticSemiring, WithIdentityElementInstance};
+    use crate::structures::ring_like::Semiring;
+
+    fn supplier_group<G: Group<T>, T>() {}
+
+    fn supplier_group_instance<G: GroupInstance<T>, T>(_: &G) {}
+
+    fn supplier_ring_instance<R: RingWithOneInstance<T>, T>(_: &R) {}
+
+    #[test]
+    fn adapters() {
+        let sum = Static::<<i32 as Semiring>::Sum>::new();
+        supplier_group_instance(&sum);
+        assert_eq!(sum.op(2, 3), 5);
+        assert_eq!(WithIdentityElementInstance::<i32>::identity(&sum), 0);
+        supplier_group::<FromInstance<Static<<i32 as Semiring>::Sum>>, i32>();
+        assert_eq!(FromInstance::<Static<<i32 as Semiring>::Multi>>::operation(2, 3), 6);
+        assert_eq!(<FromInstance<Static<<i32 as Semiring>::Multi>> as WithIdentityElement<i32>>::identity(), 1);
+        let ring = StaticSemiring::<i64>::new();
+        supplier_ring_instance(&ring);
+        assert_eq!(ring.multi().op(ring.sum().op(1, 2), 4), 12);
+    }
+}
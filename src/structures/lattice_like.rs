@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+use super::group_like::*;
+use super::ring_like::{Field, RingWithOne, unary_minus, zero, one};
+
+// `meet(x, join(x, y)) = x = join(x, meet(x, y))`
+pub trait Lattice: Sized {
+    type Meet: Semilattice<Self>;
+    type Join: Semilattice<Self>;
+}
+
+pub fn meet<T: Lattice>(operand1: T, operand2: T) -> T { T::Meet::operation(operand1, operand2) }
+
+pub fn join<T: Lattice>(operand1: T, operand2: T) -> T { T::Join::operation(operand1, operand2) }
+
+pub trait BoundedLattice = Lattice<Meet: BoundedSemilattice<Self>, Join: BoundedSemilattice<Self>>;
+
+pub fn top<T: BoundedLattice>() -> T { T::Meet::identity() }
+
+pub fn bottom<T: BoundedLattice>() -> T { T::Join::identity() }
+
+
+// marks a meet distributing over the join of its lattice, the dual law follows
+pub trait Distributive {}
+
+pub trait DistributiveLattice = Lattice<Meet: Distributive>;
+
+// `meet(x, complement(x)) = bottom`, `join(x, complement(x)) = top`
+pub trait Complemented<T> {
+    fn complement(operand: T) -> T;
+}
+
+pub trait BooleanAlgebra = BoundedLattice<Meet: Distributive + Complemented<Self>>;
+
+pub fn complement<T: BooleanAlgebra>(operand: T) -> T { T::Meet::complement(operand) }
+
+
+// marks the multiplication of a ring whose total order is compatible with the operations:
+// `x ≤ y ⇒ x + z ≤ y + z`, `0 ≤ x, 0 ≤ y ⇒ 0 ≤ x·y`, which fails for residues and floats with NaN
+pub trait Ordered {}
+
+pub trait OrderedRing = RingWithOne<Multi: Ordered> + Ord;
+
+pub trait OrderedField = Field<Multi: Ordered> + Ord;
+
+pub fn abs<T: OrderedRing>(operand: T) -> T {
+    if operand < zero() { unary_minus(operand) } else { operand }
+}
+
+// `-1`, `0` or `1`
+pub fn signum<T: OrderedRing>(operand: T) -> T {
+    match operand.cmp(&zero()) {
+        Ordering::Less => unary_minus(one()),
+        Ordering::Equal => operand,
+        Ordering::Greater => one(),
+    }
+}
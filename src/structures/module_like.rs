@@ -0,0 +1,39 @@
+use super::group_like::AbelGroup;
+use super::instances::AbelGroupInstance;
+use super::ring_like::{Field, Ring, RingWithOne};
+
+// abelian group with a scalar action: `r·(x + y) = r·x + r·y`, `(r + s)·x = r·x + s·x`, `(r·s)·x = r·(s·x)`,
+// and `1·x = x` when `R` has one
+pub trait Module<R: Ring>: Sized {
+    type Sum: AbelGroup<Self>;
+
+    fn scale(scalar: R, vector: Self) -> Self;
+}
+
+pub trait VectorSpace<F: Field> = Module<F>;
+
+// ring whose multiplication is bilinear: `r·(x·y) = (r·x)·y = x·(r·y)`
+pub trait Algebra<R: Ring> = Module<R> + RingWithOne;
+
+pub fn scale<R: Ring, V: Module<R>>(scalar: R, vector: V) -> V { V::scale(scalar, vector) }
+
+pub fn vector_zero<R: Ring, V: Module<R>>() -> V { V::Sum::identity() }
+
+pub fn vector_plus<R: Ring, V: Module<R>>(vector1: V, vector2: V) -> V { V::Sum::operation(vector1, vector2) }
+
+pub fn linear_combination<R: Ring, V: Module<R>>(terms: impl IntoIterator<Item=(R, V)>) -> V {
+    terms.into_iter().fold(vector_zero::<R, V>(), |sum, (scalar, vector)| vector_plus::<R, V>(sum, scale(scalar, vector)))
+}
+
+
+// instance counterpart of `Module`, for vectors whose zero depends on runtime parameters such as a shape
+pub trait ModuleInstance<R: Ring, V> {
+    type Sum: AbelGroupInstance<V>;
+
+    fn sum(&self) -> &Self::Sum;
+    fn scale(&self, scalar: R, vector: V) -> V;
+}
+
+pub fn linear_combination_in<R: Ring, V, M: ModuleInstance<R, V>>(module: &M, terms: impl IntoIterator<Item=(R, V)>) -> V {
+    terms.into_iter().fold(module.sum().identity(), |sum, (scalar, vector)| module.sum().op(sum, module.scale(scalar, vector)))
+}
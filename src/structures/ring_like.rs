@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+use super::basics::TryInvertible;
 use super::group_like::*;
 
 pub trait Semiring: Sized {
@@ -37,11 +39,83 @@ pub trait CommutativeRing = Ring<Multi: CommutativeSemigroup<Self>>;
 pub trait CommutativeRingWithOne = Ring<Multi: CommutativeMonoid<Self>>;
 
 
-// dividing by 0 is undefined and implementation defined
-pub trait Field = CommutativeRingWithOne<Multi: AbelGroup<Self>>;
+// skew field, dividing by 0 is undefined and implementation defined as for `Field`
+pub trait DivisionRing = RingWithOne<Multi: Group<Self>>;
+
+pub fn reciprocal<T: DivisionRing>(operand: T) -> T { T::Multi::inverse(operand) }
 
-pub fn reciprocal<T: Field>(operand: T) -> T { T::Multi::inverse(operand) }
+// `operand2⁻¹ · operand1`
+pub fn left_div<T: DivisionRing>(operand1: T, operand2: T) -> T {
+    multi(reciprocal(operand2), operand1)
+}
+
+// `operand1 · operand2⁻¹`
+pub fn right_div<T: DivisionRing>(operand1: T, operand2: T) -> T {
+    multi(operand1, reciprocal(operand2))
+}
+
+
+// dividing by 0 is undefined and implementation defined, `try_inverse` reports it instead
+pub trait Field = CommutativeRingWithOne<Multi: AbelGroup<Self>>;
 
 pub fn div<T: Field>(operand1: T, operand2: T) -> T {
     multi(operand1, reciprocal(operand2))
 }
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Hash)]
+pub struct DivisionByZero;
+
+impl Display for DivisionByZero {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Division by zero")
+    }
+}
+
+impl std::error::Error for DivisionByZero {}
+
+// fails for non-units, which in a division ring is only zero, but e.g. also `2` in `ℤ/6ℤ`
+pub fn try_reciprocal<T: UnitDetection>(operand: T) -> Result<T, DivisionByZero> {
+    try_inverse(operand).ok_or(DivisionByZero)
+}
+
+pub fn try_div<T: UnitDetection + CommutativeSemiring>(operand1: T, operand2: T) -> Result<T, DivisionByZero> {
+    Ok(multi(operand1, try_reciprocal(operand2)?))
+}
+
+
+pub fn is_zero<T: Semiring + PartialEq>(operand: &T) -> bool { *operand == zero() }
+
+pub trait UnitDetection = SemiringWithOne<Multi: TryInvertible<Self>>;
+
+// `None` for non-units, including zero
+pub fn try_inverse<T: UnitDetection>(operand: T) -> Option<T> { T::Multi::try_inverse(operand) }
+
+pub fn is_unit<T: UnitDetection + Clone>(operand: &T) -> bool { try_inverse(operand.clone()).is_some() }
+
+
+// markers and operations of the multiplication of a commutative ring with one
+
+// `x·y = 0 ⇒ x = 0 ∨ y = 0`
+pub trait NoZeroDivisors {}
+
+// a greatest common divisor, unique up to units, `gcd(0, 0) = 0`
+pub trait Gcd<T> {
+    fn gcd(operand1: T, operand2: T) -> T;
+}
+
+// every non-zero non-unit is a product of irreducibles, unique up to order and units
+pub trait UniqueFactorization {}
+
+// every ideal is generated by one element
+pub trait PrincipalIdeals {}
+
+pub trait IntegralDomain = CommutativeRingWithOne<Multi: NoZeroDivisors>;
+
+pub trait GcdDomain = IntegralDomain<Multi: NoZeroDivisors + Gcd<Self>>;
+
+pub fn gcd<T: GcdDomain>(operand1: T, operand2: T) -> T { T::Multi::gcd(operand1, operand2) }
+
+pub trait UniqueFactorizationDomain = GcdDomain<Multi: NoZeroDivisors + Gcd<Self> + UniqueFactorization>;
+
+pub trait PrincipalIdealDomain = UniqueFactorizationDomain<Multi: NoZeroDivisors + Gcd<Self> + UniqueFactorization + PrincipalIdeals>;